@@ -1,27 +1,231 @@
 mod nodes;
 
-use crate::AssertStruct;
 use crate::pattern::{
-    ComparisonOp, FieldAssertion, FieldOperation, Pattern, PatternClosure, PatternComparison,
-    PatternEnum, PatternMap, PatternRange, PatternSet, PatternSimple, PatternSlice, PatternString,
-    PatternStruct, PatternTuple, PatternWildcard, TupleElement,
+    ComparisonOp, FieldAssertion, FieldOperation, Pattern, PatternAnd, PatternApprox,
+    PatternClosure, PatternComparison, PatternEnum, PatternMap, PatternNot, PatternOr,
+    PatternQuantifier, PatternRange, PatternSet, PatternSimple, PatternSlice, PatternString,
+    PatternStruct, PatternTuple, PatternWildcard, Quantifier, TupleElement,
 };
 #[cfg(feature = "regex")]
 use crate::pattern::{PatternLike, PatternRegex};
+use crate::{AssertStruct, StandalonePattern};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned};
 use std::collections::HashSet;
 use syn::{Token, punctuated::Punctuated, spanned::Spanned};
 
-use nodes::{expand_pattern_node_ident, generate_pattern_nodes};
+use nodes::{expand_pattern_node_ident, generate_pattern_nodes, root_step};
 
+/// Expand `assert_struct!`: run the assertion and hand the report to the
+/// failure hook, which panics by default, on failure.
 pub fn expand(assert: &AssertStruct) -> TokenStream {
+    let report = expand_report(assert);
+    let with_message = expand_with_message(assert);
+
+    quote! {
+        {
+            let __report = #report;
+
+            // Check if any errors were collected
+            if !__report.is_empty() {
+                #with_message
+                ::assert_struct::__macro_support::fail(__report);
+            }
+        }
+    }
+}
+
+/// Expand `debug_assert_struct!`: the full `assert_struct!` expansion, run
+/// only when `debug_assertions` are enabled. Like `debug_assert!`, it is still
+/// type-checked in release builds, so the value stays used, but never runs.
+pub fn expand_debug(assert: &AssertStruct) -> TokenStream {
+    let assertion = expand(assert);
+
+    quote! {
+        if ::std::cfg!(debug_assertions) #assertion
+    }
+}
+
+/// Expand `check_struct!`: run the assertion and return the report as an `Err`
+/// instead of panicking.
+pub fn expand_check(assert: &AssertStruct) -> TokenStream {
+    let report = expand_report(assert);
+    let with_message = expand_with_message(assert);
+
+    quote! {
+        {
+            let __report = #report;
+
+            if __report.is_empty() {
+                ::std::result::Result::<(), ::assert_struct::ErrorReport>::Ok(())
+            } else {
+                #with_message
+                ::std::result::Result::<(), ::assert_struct::ErrorReport>::Err(__report)
+            }
+        }
+    }
+}
+
+/// Attach the custom failure message, if any. Only emitted on the failure
+/// path so the `format!` arguments are evaluated lazily, like `assert_eq!`.
+fn expand_with_message(assert: &AssertStruct) -> TokenStream {
+    match &assert.message {
+        Some(args) => quote! {
+            let __report = __report.with_message(::std::format!(#args));
+        },
+        None => quote! {},
+    }
+}
+
+/// Expand `matches_struct!`: run the assertion against a probe report and
+/// evaluate to whether it matched. Nothing is rendered.
+pub fn expand_matches(assert: &AssertStruct) -> TokenStream {
+    let value = &assert.value;
+    let report = expand_report_block(
+        &quote! { #value },
+        &assert.pattern,
+        quote! { ::assert_struct::__macro_support::ErrorReport::new_probe() },
+    );
+
+    quote! {
+        {
+            let __report = #report;
+            __report.is_empty()
+        }
+    }
+}
+
+/// Expand `pattern!`: a closure taking `&T` that reports whether the value
+/// matches. Uses a probe report, like the set-pattern predicates.
+pub fn expand_predicate(pattern: &StandalonePattern) -> TokenStream {
+    let report = expand_report_block(
+        &quote! { __assert_struct_value },
+        &pattern.pattern,
+        quote! { ::assert_struct::__macro_support::ErrorReport::new_probe() },
+    );
+
+    quote! {
+        |__assert_struct_value: &_| -> bool {
+            let __report = #report;
+            __report.is_empty()
+        }
+    }
+}
+
+/// Generate a block that runs the assertion and evaluates to the collected
+/// `ErrorReport`. Shared by every macro that runs a full assertion.
+fn expand_report(assert: &AssertStruct) -> TokenStream {
     let value = &assert.value;
-    let pattern = &assert.pattern;
 
+    // Create error report. Both values are compile-time constants:
+    // - CARGO_MANIFEST_DIR: absolute path to this package's root
+    // - file!(): path relative to the workspace root
+    // Together they let us derive the absolute source path at runtime
+    // without relying on the working directory.
+    // Inside a `#[track_caller]` function, `Location::caller()` is where that
+    // function was called; anywhere else it is this line.
+    let mut new_report = quote! {
+        ::assert_struct::__macro_support::ErrorReport::new(
+            ::std::env!("CARGO_MANIFEST_DIR"),
+            ::std::file!(),
+        )
+        .with_caller(::std::panic::Location::caller(), ::std::line!())
+    };
+
+    // Embed the invocation's text too, so the snippet can still be rendered
+    // when the file isn't there at runtime (packaged test binaries, containers,
+    // remapped paths).
+    if let Some((first_line, text)) = embedded_source(&assert.source) {
+        new_report = quote! {
+            {
+                static __SOURCE: ::assert_struct::__macro_support::EmbeddedSource =
+                    ::assert_struct::__macro_support::EmbeddedSource {
+                        first_line: #first_line,
+                        text: #text,
+                    };
+                #new_report.with_source(&__SOURCE)
+            }
+        };
+    }
+
+    expand_report_block(&quote! { #value }, &assert.pattern, new_report)
+}
+
+/// Reconstruct the source text of `tokens` from their spans: each token is
+/// written at its line and column, so the node locations index into the
+/// result as they would into the file. Comments are lost, and the text starts
+/// at the first token rather than at the macro name.
+///
+/// Returns the 1-indexed line of the first token and the text, or `None` when
+/// the spans don't describe real source in order, e.g. for tokens produced by
+/// another macro.
+fn embedded_source(tokens: &TokenStream) -> Option<(u32, String)> {
+    fn collect(tokens: &TokenStream, out: &mut Vec<(proc_macro2::LineColumn, String)>) {
+        for token in tokens.clone() {
+            match token {
+                proc_macro2::TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                        proc_macro2::Delimiter::Brace => ("{", "}"),
+                        proc_macro2::Delimiter::Bracket => ("[", "]"),
+                        proc_macro2::Delimiter::None => ("", ""),
+                    };
+                    if !open.is_empty() {
+                        out.push((group.span_open().start(), open.to_string()));
+                    }
+                    collect(&group.stream(), out);
+                    if !close.is_empty() {
+                        out.push((group.span_close().start(), close.to_string()));
+                    }
+                }
+                token => out.push((token.span().start(), token.to_string())),
+            }
+        }
+    }
+
+    let mut pieces = Vec::new();
+    collect(tokens, &mut pieces);
+
+    let first_line = pieces.first()?.0.line;
+    if first_line == 0 {
+        return None;
+    }
+
+    let mut text = String::new();
+    let (mut line, mut column) = (first_line, 0);
+    for (start, piece) in pieces {
+        if start.line < line || (start.line == line && start.column < column) {
+            return None;
+        }
+        if start.line > line {
+            text.extend(std::iter::repeat_n('\n', start.line - line));
+            (line, column) = (start.line, 0);
+        }
+        text.extend(std::iter::repeat_n(' ', start.column - column));
+        text.push_str(&piece);
+
+        match piece.rfind('\n') {
+            Some(newline) => {
+                line += piece.matches('\n').count();
+                column = piece[newline + 1..].chars().count();
+            }
+            None => column = start.column + piece.chars().count(),
+        }
+    }
+
+    Some((first_line as u32, text))
+}
+
+/// Generate the pattern node statics and assertion code for `pattern`, run
+/// against `value` and collected into the report built by `new_report`.
+fn expand_report_block(
+    value: &TokenStream,
+    pattern: &Pattern,
+    new_report: TokenStream,
+) -> TokenStream {
     // Generate pattern nodes using the node IDs from the patterns
     let mut node_defs = Vec::new();
-    let root_ref = generate_pattern_nodes(pattern, &mut node_defs, None);
+    let root_ref = generate_pattern_nodes(pattern, &mut node_defs, None, root_step(value));
 
     // Generate static declarations for all nodes
     let node_constants: Vec<TokenStream> = node_defs
@@ -34,14 +238,14 @@ pub fn expand(assert: &AssertStruct) -> TokenStream {
         })
         .collect();
 
-    let assertion = expand_pattern_assertion(&quote! { #value }, pattern);
+    let assertion = expand_pattern_assertion(value, pattern);
 
     // Wrap in a block to avoid variable name conflicts
     quote! {
         {
             // Suppress clippy warnings that are expected in macro-generated code
-            #[allow(unused_assignments, clippy::neg_cmp_op_on_partial_ord, clippy::op_ref, clippy::zero_prefixed_literal, clippy::bool_comparison, clippy::redundant_pattern_matching, clippy::useless_asref)]
-            let __assert_struct_result = {
+            #[allow(unused_assignments, clippy::neg_cmp_op_on_partial_ord, clippy::op_ref, clippy::zero_prefixed_literal, clippy::bool_comparison, clippy::redundant_pattern_matching, clippy::useless_asref, clippy::unnecessary_cast, clippy::comparison_to_empty, clippy::needless_borrow)]
+            let __assert_struct_report = {
                 use std::convert::AsRef;
 
                 // Generate all node constants
@@ -50,24 +254,13 @@ pub fn expand(assert: &AssertStruct) -> TokenStream {
                 // Store the pattern tree root
                 const __PATTERN_TREE: &::assert_struct::__macro_support::PatternNode = &#root_ref;
 
-                // Create error report. Both values are compile-time constants:
-                // - CARGO_MANIFEST_DIR: absolute path to this package's root
-                // - file!(): path relative to the workspace root
-                // Together they let us derive the absolute source path at runtime
-                // without relying on the working directory.
-                let mut __report = ::assert_struct::__macro_support::ErrorReport::new(
-                    ::std::env!("CARGO_MANIFEST_DIR"),
-                    ::std::file!(),
-                );
+                let mut __report = #new_report;
 
                 #assertion
 
-                // Check if any errors were collected
-                if !__report.is_empty() {
-                    panic!("{}", __report);
-                }
+                __report
             };
-            __assert_struct_result
+            __assert_struct_report
         }
     }
 }
@@ -121,9 +314,29 @@ fn expand_pattern_assertion(value_expr: &TokenStream, pattern: &Pattern) -> Toke
             expand_map_assertion(value_expr, map_pattern)
         }
         Pattern::Set(set_pattern) => {
-            // Generate set assertion with backtracking
+            // Generate set assertion with bipartite matching
             expand_set_assertion(value_expr, set_pattern)
         }
+        Pattern::Or(or_pattern) => {
+            // Try each alternative against a probe report
+            expand_or_assertion(value_expr, or_pattern)
+        }
+        Pattern::Not(not_pattern) => {
+            // Run the inner pattern against a probe report and invert
+            expand_not_assertion(value_expr, not_pattern)
+        }
+        Pattern::And(and_pattern) => {
+            // Run every conjunct into the same report
+            expand_and_assertion(value_expr, and_pattern)
+        }
+        Pattern::Approx(approx_pattern) => {
+            // Compare floats within a tolerance at runtime
+            expand_approx_assertion(value_expr, approx_pattern)
+        }
+        Pattern::Quantifier(quantifier_pattern) => {
+            // Apply the inner pattern to every element
+            expand_quantifier_assertion(value_expr, quantifier_pattern)
+        }
     }
 }
 
@@ -153,10 +366,13 @@ fn expand_struct_assertion(value_expr: &TokenStream, pattern: &PatternStruct) ->
         })
         .collect();
 
-    let rest_pattern = if rest {
-        quote! { , .. }
-    } else {
+    let rest_pattern = if !rest {
         quote! {}
+    } else if field_names.is_empty() {
+        // `Variant { .. }` with no fields listed
+        quote! { .. }
+    } else {
+        quote! { , .. }
     };
 
     let field_assertions: Vec<_> = fields
@@ -258,6 +474,20 @@ fn expand_field_assertion(base: &TokenStream, field_assertion: &FieldAssertion)
     expand_pattern_assertion(&expr, field_pattern)
 }
 
+/// Run `assertion` in a nested scope: `assert_struct!` failures inside the
+/// closure it calls are added to this report as failures of the node
+/// `node_id`, rather than panicking on their own.
+fn with_nested_scope(assertion: TokenStream, node_id: usize) -> TokenStream {
+    let node_ident = expand_pattern_node_ident(node_id);
+    quote! {
+        {
+            let __nested = ::assert_struct::__macro_support::NestedScope::enter();
+            #assertion
+            __nested.exit(&mut __report, &#node_ident);
+        }
+    }
+}
+
 /// Apply field operations to a value expression
 /// This generates the appropriate dereferencing, method calls, nested field access, index operations, or await
 ///
@@ -413,7 +643,10 @@ fn expand_comparison_assertion(
             ComparisonOp::GreaterEqual(_) => {
                 quote_spanned! {span=> (#value_expr).ge(&(#expected)) }
             }
-            ComparisonOp::Equal(_) => quote_spanned! {span=> (#value_expr).eq(&(#expected)) },
+            // Both values are bound once so the diff can reuse them
+            ComparisonOp::Equal(_) => {
+                quote_spanned! {span=> (*__assert_struct_actual).eq(__assert_struct_expected) }
+            }
             ComparisonOp::NotEqual(_) => quote_spanned! {span=> (#value_expr).ne(&(#expected)) },
         }
     };
@@ -425,6 +658,26 @@ fn expand_comparison_assertion(
         quote!(None)
     };
 
+    if matches!(op, ComparisonOp::Equal(_)) {
+        let error_push = generate_error_push_with_diff(
+            span,
+            expected_value,
+            &quote!(__assert_struct_expected),
+            pattern.node_id,
+        );
+
+        return quote_spanned! {span=>
+            {
+                let __assert_struct_expected = &(#expected);
+                let __assert_struct_actual = &(#value_expr);
+                #[allow(clippy::nonminimal_bool)]
+                if !(#comparison) {
+                    #error_push
+                }
+            }
+        };
+    }
+
     let error_push = generate_error_push(
         span,
         quote!(format!("{:?}", #value_expr)),
@@ -455,6 +708,46 @@ fn expand_enum_assertion(value_expr: &TokenStream, pattern: &PatternEnum) -> Tok
 
     // Special handling for unit variants (empty elements)
     if elements.is_empty() {
+        // A constant is also a value, so it can be diffed. The name alone
+        // can't tell it from an all-caps unit variant: the path is typed like
+        // the asserted value, in case it is a generic enum's variant, and a
+        // value that prints as the variant's name is reported as a variant.
+        if let Some(name) = constant_name(variant_path) {
+            let variant_push = generate_error_push(
+                span,
+                quote!(format!("{:?}", __assert_struct_actual)),
+                quote!(None),
+                pattern.node_id,
+            );
+            let diff_push = generate_error_push_with_diff(
+                span,
+                quote!(None),
+                &quote!(__assert_struct_expected),
+                pattern.node_id,
+            );
+            return quote_spanned! {span=>
+                {
+                    let __assert_struct_actual = &(#value_expr);
+                    if !matches!(*__assert_struct_actual, #variant_path) {
+                        let __assert_struct_expected = ::assert_struct::__macro_support::typed_like(
+                            &(#variant_path),
+                            __assert_struct_actual,
+                        );
+                        #[allow(unused_imports)]
+                        use ::assert_struct::__macro_support::{DebugIfAvailable as _, NoDebug as _};
+                        let __assert_struct_is_variant =
+                            (&&::assert_struct::__macro_support::MaybeDebug(__assert_struct_expected))
+                                .pretty_debug()
+                                .is_some_and(|__debug| __debug == #name);
+                        if __assert_struct_is_variant {
+                            #variant_push
+                        } else {
+                            #diff_push
+                        }
+                    }
+                }
+            };
+        }
         quote_spanned! {span=>
             if !matches!(#value_expr, #variant_path) {
                 #error_push
@@ -507,12 +800,15 @@ fn expand_string_assertion(value_expr: &TokenStream, pattern: &PatternString) ->
 
     // String patterns always use .as_ref() to handle String/&str matching
     let span = lit.span();
-    let error_push = generate_error_push(
-        span,
-        quote!(format!("{:?}", actual)),
-        quote!(None),
-        pattern.node_id,
-    );
+    let node_ident = expand_pattern_node_ident(pattern.node_id);
+    let error_push = quote_spanned! {span=>
+        if __report.records_values() {
+            __report.push(&#node_ident, format!("{:?}", actual), None);
+            __report.attach_text_diff(#lit, actual);
+        } else {
+            __report.push_unformatted(&#node_ident);
+        }
+    };
 
     quote_spanned! {span=> {
         // Take a reference to the expression result so that:
@@ -547,18 +843,21 @@ fn expand_simple_assertion(actual: &TokenStream, pattern: &PatternSimple) -> Tok
 }
 
 /// Generate slice assertion with error collection
+///
+/// Each element assertion runs between `push_index`/`pop_index`, so failures
+/// are labelled with the element's position in the value, e.g. `items[2]`.
+/// Elements after a `..` are indexed from the end of the slice.
 fn expand_slice_assertion(value_expr: &TokenStream, pattern: &PatternSlice) -> TokenStream {
     let mut pattern_parts = Vec::new();
     let mut bindings_and_assertions = Vec::new();
+    let mut after_rest = false;
+    let total = pattern.elements.len();
 
     for (i, elem) in pattern.elements.iter().enumerate() {
         match elem {
-            Pattern::Range(PatternRange {
-                expr: syn::Expr::Range(r),
-                ..
-            }) if r.start.is_none() && r.end.is_none() => {
-                // RangeFull (..) in slice context is a rest pattern
+            _ if PatternSlice::is_rest(elem) => {
                 pattern_parts.push(quote! { .. });
+                after_rest = true;
             }
             Pattern::Wildcard(PatternWildcard { .. }) => {
                 // Wildcard pattern matches any single element without binding
@@ -568,29 +867,39 @@ fn expand_slice_assertion(value_expr: &TokenStream, pattern: &PatternSlice) -> T
                 let binding = quote::format_ident!("__elem_{}", i);
                 pattern_parts.push(quote! { #binding });
 
+                let index = if after_rest {
+                    let from_end = total - i;
+                    quote! { __slice.len() - #from_end }
+                } else {
+                    quote! { #i }
+                };
                 let assertion = expand_pattern_assertion(&quote! { #binding }, elem);
-                bindings_and_assertions.push(assertion);
+                bindings_and_assertions.push(quote! {
+                    {
+                        __report.push_index(#index);
+                        #assertion
+                        __report.pop_index();
+                    }
+                });
             }
         }
     }
 
-    // Convert Vec to slice for matching
-    let slice_expr = quote! { (#value_expr).as_slice() };
-
-    let error_push = generate_error_push(
-        proc_macro2::Span::call_site(),
-        quote!(format!("{:?}", &#value_expr)),
-        quote!(None),
-        pattern.node_id,
-    );
+    let node_ident = expand_pattern_node_ident(pattern.node_id);
 
+    // Convert Vec to slice for matching. The arm only fails on length, as every
+    // element is bound or skipped; the elements are checked inside the arm.
     quote! {
-        match #slice_expr {
-            [#(#pattern_parts),*] => {
+        match (#value_expr).as_slice() {
+            __slice @ [#(#pattern_parts),*] => {
                 #(#bindings_and_assertions)*
             }
-            _ => {
-                #error_push
+            __slice => {
+                ::assert_struct::__macro_support::slice_length_mismatch(
+                    __slice,
+                    &mut __report,
+                    &#node_ident,
+                );
             }
         }
     }
@@ -653,62 +962,71 @@ fn expand_closure_assertion(value_expr: &TokenStream, pattern: &PatternClosure)
         pattern.node_id,
     );
 
-    quote_spanned! {span=>
+    let assertion = quote_spanned! {span=>
         {
             if !::assert_struct::__macro_support::check_closure_condition(#value_expr, #closure) {
                 #error_push
             }
         }
-    }
+    };
+    with_nested_scope(assertion, pattern.node_id)
 }
 
 /// Generate map assertion with error collection using duck typing
-/// Assumes map types have len() -> usize and get(&K) -> Option<&V> methods
+/// Assumes map types have len() -> usize and get(&K) -> Option<&V> methods,
+/// plus keys() -> impl Iterator<Item = &K> for exact patterns
+///
+/// Exact patterns report differing key sets as a single failure on the map,
+/// summarising the missing, unexpected and mismatched keys.
 fn expand_map_assertion(value_expr: &TokenStream, pattern: &PatternMap) -> TokenStream {
     let entries = &pattern.entries;
     let rest = pattern.rest;
 
-    // Use span from first entry or default span if empty
-    let map_span = entries
-        .first()
-        .map(|(key, _)| key.span())
-        .unwrap_or_else(proc_macro2::Span::call_site);
-
-    // Generate length check assertion for exact matching (when no rest pattern)
-    let len_check = if !rest {
-        let expected_len = entries.len();
-        let error_push = generate_error_push(
-            map_span,
-            quote!(format!("map with {} entries", (#value_expr).len())),
-            quote!(Some(format!("{} entries", #expected_len))),
-            pattern.node_id,
-        );
-        quote_spanned! {map_span=>
-            // Check exact length for maps without rest pattern
-            if (#value_expr).len() != #expected_len {
-                #error_push
-            }
-        }
-    } else {
-        quote! {}
-    };
+    // A key may appear more than once, with every value pattern applied to its
+    // value, but it is only one entry of the map
+    let mut seen_keys = HashSet::new();
 
     // Generate key-value assertions
     let key_value_assertions: Vec<TokenStream> = entries
         .iter()
         .map(|(key, value_pattern)| {
             let key_str = quote! { #key }.to_string();
+            let first_occurrence = seen_keys.insert(key_str.clone());
 
             let span = key.span();
             let pattern_assertion =
                 expand_pattern_assertion(&quote! { __map_value }, value_pattern);
 
-            let missing_key_error = generate_error_push(
-                span,
-                quote!("missing key".to_string()),
-                quote!(Some(format!("key present: {}", #key_str))),
-                pattern.node_id,
-            );
+            let missing_key_error = if rest {
+                generate_error_push(
+                    span,
+                    quote!("missing key".to_string()),
+                    quote!(Some(format!("key present: {}", #key_str))),
+                    pattern.node_id,
+                )
+            } else if first_occurrence {
+                quote_spanned! {span=> __map_missing.push(#key_str); }
+            } else {
+                quote! {}
+            };
+
+            let value_check = if rest {
+                pattern_assertion
+            } else {
+                let count_present = if first_occurrence {
+                    quote! { __map_present += 1; }
+                } else {
+                    quote! {}
+                };
+                quote_spanned! {span=>
+                    #count_present
+                    let __map_errors = __report.len();
+                    #pattern_assertion
+                    if __report.len() > __map_errors && !__map_mismatched.contains(&#key_str) {
+                        __map_mismatched.push(#key_str);
+                    }
+                }
+            };
 
             // Handle different key types for duck typing
             let get_expr = if matches!(
@@ -722,7 +1040,7 @@ fn expand_map_assertion(value_expr: &TokenStream, pattern: &PatternMap) -> Token
                 quote_spanned! {span=> (#value_expr).get(&(#key).to_string()) }
             } else {
                 // For other expressions, try as-is
-                quote_spanned! {span=> (#value_expr).get(&#key) }
+                quote_spanned! {span=> (#value_expr).get(&(#key)) }
             };
 
             quote_spanned! {span=>
@@ -730,7 +1048,7 @@ fn expand_map_assertion(value_expr: &TokenStream, pattern: &PatternMap) -> Token
                 match #get_expr {
                     Some(__map_value) => {
                         // Apply pattern assertion to the value
-                        #pattern_assertion
+                        #value_check
                     }
                     None => {
                         #missing_key_error
@@ -740,17 +1058,78 @@ fn expand_map_assertion(value_expr: &TokenStream, pattern: &PatternMap) -> Token
         })
         .collect();
 
+    if rest {
+        return quote! {
+            #(#key_value_assertions)*
+        };
+    }
+
+    // A key of the value is expected if it equals one of the pattern's keys
+    let expected_key = if entries.is_empty() {
+        quote! { false }
+    } else {
+        let comparisons = entries.iter().map(|(key, _)| {
+            let span = key.span();
+            quote_spanned! {span=> **__map_key == (#key) }
+        });
+        quote! { #(#comparisons)||* }
+    };
+    // Point type errors, e.g. a value without `keys()`, at the map pattern
+    let len_expr = quote_spanned! {pattern.span=> (#value_expr).len() };
+    let keys_expr = quote_spanned! {pattern.span=> (#value_expr).keys() };
+    let unexpected_keys = quote! {
+        #keys_expr
+            .filter(|__map_key| !(#expected_key))
+            .collect()
+    };
+    // Every distinct literal key of the pattern that is present accounts for
+    // one entry, so the keys only need walking when there are more. Distinct
+    // expressions may still evaluate to the same key, so those always walk.
+    let unexpected_keys = if entries
+        .iter()
+        .all(|(key, _)| matches!(key, syn::Expr::Lit(_)))
+    {
+        quote! {
+            if #len_expr != __map_present {
+                #unexpected_keys
+            } else {
+                Vec::new()
+            }
+        }
+    } else {
+        unexpected_keys
+    };
+    let node_ident = expand_pattern_node_ident(pattern.node_id);
+
     quote! {
-        #len_check
-        #(#key_value_assertions)*
+        {
+            #[allow(unused_mut)]
+            let mut __map_missing: Vec<&'static str> = Vec::new();
+            #[allow(unused_mut)]
+            let mut __map_mismatched: Vec<&'static str> = Vec::new();
+            // Only read when every key is a literal
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            let mut __map_present: usize = 0;
+            #(#key_value_assertions)*
+            let __map_unexpected: Vec<_> = #unexpected_keys;
+            if !__map_missing.is_empty() || !__map_unexpected.is_empty() {
+                ::assert_struct::__macro_support::map_keys_mismatch(
+                    &mut __report,
+                    &#node_ident,
+                    &__map_missing,
+                    &__map_unexpected,
+                    &__map_mismatched,
+                );
+            }
+        }
     }
 }
 
-/// Generate set assertion using backtracking to match patterns in any order.
+/// Generate set assertion matching patterns to elements in any order.
 ///
 /// Each element pattern becomes a predicate closure that shadows `__report` with a
 /// probe report, allowing the existing assertion code to be reused unchanged. The
-/// runtime `set_match` function owns the length check and backtracking algorithm.
+/// runtime `set_match` function owns the length check and matching algorithm.
 fn expand_set_assertion(value_expr: &TokenStream, pattern: &PatternSet) -> TokenStream {
     let elements = &pattern.elements;
     let rest = pattern.rest;
@@ -759,9 +1138,9 @@ fn expand_set_assertion(value_expr: &TokenStream, pattern: &PatternSet) -> Token
     // Generate one named predicate binding per element pattern.
     // Each closure:
     //   1. Looks up the element by index from __set_coll (captured by ref)
-    //   2. Shadows __report with a fresh probe report
+    //   2. Shadows __report with the report it is handed
     //   3. Runs the generated assertion (which writes to the local __report)
-    //   4. Returns true iff no errors were pushed (i.e. the pattern matched)
+    //   4. Returns the report; the pattern matched iff it is empty
     let pred_names: Vec<_> = (0..elements.len())
         .map(|i| quote::format_ident!("__set_pred_{}", i))
         .collect();
@@ -772,12 +1151,12 @@ fn expand_set_assertion(value_expr: &TokenStream, pattern: &PatternSet) -> Token
         .map(|(elem, name)| {
             let assertion = expand_pattern_assertion(&quote! { __set_elem }, elem);
             quote! {
-                let #name = |__set_idx: usize| -> bool {
+                let #name = |__set_idx: usize,
+                             #[allow(unused_mut)] mut __report: ::assert_struct::__macro_support::ErrorReport|
+                 -> ::assert_struct::__macro_support::ErrorReport {
                     let __set_elem = __set_coll[__set_idx];
-                    #[allow(unused_mut)]
-                    let mut __report = ::assert_struct::__macro_support::ErrorReport::new_probe();
                     #assertion
-                    __report.is_empty()
+                    __report
                 };
             }
         })
@@ -787,7 +1166,10 @@ fn expand_set_assertion(value_expr: &TokenStream, pattern: &PatternSet) -> Token
         {
             let __set_coll: ::std::vec::Vec<_> = (&(#value_expr)).into_iter().collect();
             #(#pred_defs)*
-            let __set_preds: &[&dyn ::std::ops::Fn(usize) -> bool] = &[#(&#pred_names),*];
+            let __set_preds: &[&dyn ::std::ops::Fn(
+                usize,
+                ::assert_struct::__macro_support::ErrorReport,
+            ) -> ::assert_struct::__macro_support::ErrorReport] = &[#(&#pred_names),*];
             ::assert_struct::__macro_support::set_match(
                 __set_coll.len(),
                 #rest,
@@ -799,7 +1181,248 @@ fn expand_set_assertion(value_expr: &TokenStream, pattern: &PatternSet) -> Token
     }
 }
 
+/// Generate alternation assertion: succeeds if any alternative matches.
+///
+/// Each alternative runs against its own probe report, like the set-pattern
+/// predicates, and the alternatives are chained with `||` so evaluation stops
+/// at the first match. Only the alternation itself is reported on failure.
+fn expand_or_assertion(value_expr: &TokenStream, pattern: &PatternOr) -> TokenStream {
+    // Evaluate the value once and let every alternative match against the same place
+    let alt_value = quote! { (*__assert_struct_alt) };
+
+    let alternatives: Vec<TokenStream> = pattern
+        .alternatives
+        .iter()
+        .map(|alt| {
+            let assertion = expand_pattern_assertion(&alt_value, alt);
+            quote! {
+                {
+                    #[allow(unused_mut)]
+                    let mut __report = ::assert_struct::__macro_support::ErrorReport::new_probe();
+                    #assertion
+                    __report.is_empty()
+                }
+            }
+        })
+        .collect();
+
+    let error_push = generate_error_push(
+        proc_macro2::Span::call_site(),
+        quote!(format!("{:?}", #alt_value)),
+        quote!(None),
+        pattern.node_id,
+    );
+
+    quote! {
+        {
+            let __assert_struct_alt = &(#value_expr);
+            if !(#(#alternatives)||*) {
+                #error_push
+            }
+        }
+    }
+}
+
+/// Generate quantifier assertion over any iterable.
+///
+/// - `all` runs the inner assertion on every element into the main report,
+///   with the element index pushed so each failure names its element.
+/// - `any` and `none` probe each element like the set-pattern predicates; `any`
+///   reports once if nothing matched, `none` reports every matching element.
+fn expand_quantifier_assertion(
+    value_expr: &TokenStream,
+    pattern: &PatternQuantifier,
+) -> TokenStream {
+    let assertion = expand_pattern_assertion(&quote! { __quant_elem }, &pattern.inner);
+    let elements = quote! { (&(#value_expr)).into_iter().enumerate() };
+
+    let probe = quote! {
+        {
+            #[allow(unused_mut)]
+            let mut __report = ::assert_struct::__macro_support::ErrorReport::new_probe();
+            #assertion
+            __report.is_empty()
+        }
+    };
+
+    let any_error_push = generate_error_push(
+        proc_macro2::Span::call_site(),
+        quote!(format!(
+            "{} element{}",
+            __quant_len,
+            if __quant_len == 1 { "" } else { "s" },
+        )),
+        quote!(None),
+        pattern.node_id,
+    );
+    let none_error_push = generate_error_push(
+        proc_macro2::Span::call_site(),
+        quote!(format!("{:?}", __quant_elem)),
+        quote!(None),
+        pattern.node_id,
+    );
+
+    match pattern.quantifier {
+        Quantifier::All => quote! {
+            for (__quant_idx, __quant_elem) in #elements {
+                __report.push_index(__quant_idx);
+                #assertion
+                __report.pop_index();
+            }
+        },
+        Quantifier::Any => quote! {
+            {
+                let mut __quant_len = 0usize;
+                let mut __quant_matched = false;
+                for (__quant_idx, __quant_elem) in #elements {
+                    __quant_len = __quant_idx + 1;
+                    if #probe {
+                        __quant_matched = true;
+                        break;
+                    }
+                }
+                if !__quant_matched {
+                    #any_error_push
+                }
+            }
+        },
+        Quantifier::None => quote! {
+            for (__quant_idx, __quant_elem) in #elements {
+                if #probe {
+                    __report.push_index(__quant_idx);
+                    #none_error_push
+                    __report.pop_index();
+                }
+            }
+        },
+    }
+}
+
+/// Generate approximate equality assertion. The comparison and error
+/// reporting live in the runtime `approx_match` helper.
+fn expand_approx_assertion(value_expr: &TokenStream, pattern: &PatternApprox) -> TokenStream {
+    let expected = &pattern.expected;
+    let tolerance = &pattern.tolerance;
+    let relative = pattern.relative;
+    let node_ident = expand_pattern_node_ident(pattern.node_id);
+    let span = pattern.span;
+
+    quote_spanned! {span=>
+        ::assert_struct::__macro_support::approx_match(
+            &(#value_expr),
+            (#expected) as f64,
+            (#tolerance) as f64,
+            #relative,
+            &mut __report,
+            &#node_ident,
+        );
+    }
+}
+
+/// Generate conjunction assertion: every conjunct runs against the same value
+/// and pushes its own errors, so each failing conjunct is reported separately.
+fn expand_and_assertion(value_expr: &TokenStream, pattern: &PatternAnd) -> TokenStream {
+    // Evaluate the value once and let every conjunct match against the same place
+    let conj_value = quote! { (*__assert_struct_conj) };
+
+    let conjuncts: Vec<TokenStream> = pattern
+        .conjuncts
+        .iter()
+        .map(|conjunct| expand_pattern_assertion(&conj_value, conjunct))
+        .collect();
+
+    quote! {
+        {
+            let __assert_struct_conj = &(#value_expr);
+            #(#conjuncts)*
+        }
+    }
+}
+
+/// Generate negation assertion: fails if the inner pattern matches.
+fn expand_not_assertion(value_expr: &TokenStream, pattern: &PatternNot) -> TokenStream {
+    // Evaluate the value once for both the inner pattern and the error message
+    let not_value = quote! { (*__assert_struct_not) };
+    let assertion = expand_pattern_assertion(&not_value, &pattern.inner);
+
+    let error_push = generate_error_push(
+        pattern.span,
+        quote!(format!("{:?}", #not_value)),
+        quote!(None),
+        pattern.node_id,
+    );
+
+    quote! {
+        {
+            let __assert_struct_not = &(#value_expr);
+            let __assert_struct_inner_matched = {
+                #[allow(unused_mut)]
+                let mut __report = ::assert_struct::__macro_support::ErrorReport::new_probe();
+                #assertion
+                __report.is_empty()
+            };
+            if __assert_struct_inner_matched {
+                #error_push
+            }
+        }
+    }
+}
+
+/// The name of the constant a standalone path pattern may refer to rather
+/// than a unit variant, going by the `SCREAMING_CASE` naming convention.
+fn constant_name(path: &syn::Path) -> Option<String> {
+    let name = path.segments.last()?.ident.to_string();
+    (name.chars().any(char::is_alphabetic) && !name.chars().any(char::is_lowercase)).then_some(name)
+}
+
+/// Like [`generate_error_push`] for patterns that compare against a whole
+/// value. Also attaches a diff of the two values: a text diff when both are
+/// strings, otherwise a diff of their pretty-printed `Debug` output, which the
+/// report shows when it spans several lines. The expected value is only
+/// formatted if its type implements `Debug`.
+///
+/// The caller binds a reference to the actual value to `__assert_struct_actual`,
+/// so it is evaluated only once. `expected` may refer to it too.
+fn generate_error_push_with_diff(
+    span: proc_macro2::Span,
+    expected_value: TokenStream,
+    expected: &TokenStream,
+    node_id: usize,
+) -> TokenStream {
+    let node_ident = expand_pattern_node_ident(node_id);
+    quote_spanned! {span=>
+        if __report.records_values() {
+            __report.push(&#node_ident, format!("{:?}", __assert_struct_actual), #expected_value);
+            {
+                // Only one trait of each pair ends up being used
+                #[allow(unused_imports)]
+                use ::assert_struct::__macro_support::{
+                    DebugIfAvailable as _, NoDebug as _, NoText as _, TextIfAvailable as _,
+                };
+                match (
+                    (&&::assert_struct::__macro_support::MaybeText(#expected)).text(),
+                    (&&::assert_struct::__macro_support::MaybeText(__assert_struct_actual)).text(),
+                ) {
+                    (Some(__expected_text), Some(__actual_text)) => {
+                        __report.attach_text_diff(&__expected_text, &__actual_text)
+                    }
+                    _ => __report.attach_diff(
+                        (&&::assert_struct::__macro_support::MaybeDebug(#expected)).pretty_debug(),
+                        format!("{:#?}", __assert_struct_actual),
+                    ),
+                }
+            }
+        } else {
+            __report.push_unformatted(&#node_ident);
+        }
+    }
+}
+
 /// Generate the error context creation and push code
+///
+/// The actual and expected values are only formatted when the report records
+/// them; probe reports just count the failure, so set and alternation matching
+/// never pay for `Debug` output that is thrown away.
 fn generate_error_push(
     span: proc_macro2::Span,
     actual_value: TokenStream,
@@ -808,6 +1431,10 @@ fn generate_error_push(
 ) -> TokenStream {
     let node_ident = expand_pattern_node_ident(node_id);
     quote_spanned! {span=>
-        __report.push(&#node_ident, #actual_value, #expected_value);
+        if __report.records_values() {
+            __report.push(&#node_ident, #actual_value, #expected_value);
+        } else {
+            __report.push_unformatted(&#node_ident);
+        }
     }
 }
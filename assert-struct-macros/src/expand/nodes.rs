@@ -4,9 +4,10 @@
 //! displaying helpful error messages when assertions fail.
 
 use crate::pattern::{
-    ComparisonOp, Pattern, PatternClosure, PatternComparison, PatternEnum, PatternMap,
-    PatternRange, PatternSet, PatternSimple, PatternSlice, PatternString, PatternStruct,
-    PatternTuple, PatternWildcard, TupleElement,
+    ComparisonOp, Pattern, PatternAnd, PatternApprox, PatternClosure, PatternComparison,
+    PatternEnum, PatternMap, PatternNot, PatternOr, PatternQuantifier, PatternRange, PatternSet,
+    PatternSimple, PatternSlice, PatternString, PatternStruct, PatternTuple, PatternWildcard,
+    Quantifier, TupleElement, source_text,
 };
 #[cfg(feature = "regex")]
 use crate::pattern::{PatternLike, PatternRegex};
@@ -18,37 +19,28 @@ pub(super) fn expand_pattern_node_ident(node_id: usize) -> Ident {
     Ident::new(&format!("__PATTERN_NODE_{}", node_id), Span::call_site())
 }
 
+/// Build a runtime `PathStep`, e.g. `path_step(quote!(Same))`
+fn path_step(step: TokenStream) -> TokenStream {
+    quote! { ::assert_struct::__macro_support::PathStep::#step }
+}
+
+/// The path step of the root node: the value given to the macro
+pub(super) fn root_step(value: &TokenStream) -> TokenStream {
+    let text = source_text(value);
+    path_step(quote!(Root(#text)))
+}
+
 /// Generate pattern nodes using the IDs already in patterns
+///
+/// `step` records how the node is reached from its parent, used to build the
+/// field path of failures.
 pub(super) fn generate_pattern_nodes(
     pattern: &Pattern,
     node_defs: &mut Vec<(usize, TokenStream)>,
     parent_ident: Option<&Ident>,
+    step: TokenStream,
 ) -> TokenStream {
-    // Get the node_id from the pattern itself
-    let node_id = match pattern {
-        Pattern::Simple(PatternSimple { node_id, .. })
-        | Pattern::String(PatternString { node_id, .. })
-        | Pattern::Struct(PatternStruct { node_id, .. })
-        | Pattern::Enum(PatternEnum { node_id, .. })
-        | Pattern::Tuple(PatternTuple { node_id, .. })
-        | Pattern::Slice(PatternSlice { node_id, .. })
-        | Pattern::Comparison(PatternComparison { node_id, .. })
-        | Pattern::Range(PatternRange { node_id, .. })
-        | Pattern::Wildcard(PatternWildcard { node_id })
-        | Pattern::Closure(PatternClosure { node_id, .. })
-        | Pattern::Map(PatternMap { node_id, .. })
-        | Pattern::Set(PatternSet { node_id, .. }) => *node_id,
-        #[cfg(feature = "regex")]
-        Pattern::Regex(PatternRegex { node_id, .. })
-        | Pattern::Like(PatternLike { node_id, .. }) => *node_id,
-    };
-
-    // Rest elements inside slice patterns (usize::MAX node_id) are not meaningful
-    // for error reporting — they contribute only the `rest: bool` flag on the
-    // parent Slice node, not a child PatternNode constant of their own.
-    if node_id == usize::MAX {
-        return quote! {};
-    }
+    let node_id = pattern.node_id();
 
     let node_ident = Ident::new(&format!("__PATTERN_NODE_{}", node_id), Span::call_site());
 
@@ -70,6 +62,7 @@ pub(super) fn generate_pattern_nodes(
                         value: #value_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -85,6 +78,7 @@ pub(super) fn generate_pattern_nodes(
                         value: #value_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -121,6 +115,7 @@ pub(super) fn generate_pattern_nodes(
                         value: #value_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -136,6 +131,7 @@ pub(super) fn generate_pattern_nodes(
                         pattern: #pattern_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -152,6 +148,7 @@ pub(super) fn generate_pattern_nodes(
                         pattern: #pattern_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -168,6 +165,7 @@ pub(super) fn generate_pattern_nodes(
                         expr: #expr_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -180,6 +178,7 @@ pub(super) fn generate_pattern_nodes(
                 ::assert_struct::__macro_support::PatternNode {
                     kind: ::assert_struct::__macro_support::NodeKind::Wildcard,
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -195,6 +194,7 @@ pub(super) fn generate_pattern_nodes(
                         closure: #closure_str,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -203,14 +203,15 @@ pub(super) fn generate_pattern_nodes(
             }
         }
         Pattern::Enum(PatternEnum { path, elements, .. }) => {
+            // The argument of a single-argument variant like `Some(..)` is
+            // the variant's value, so it adds no step of its own
+            let positional = elements.len() > 1;
             let child_refs: Vec<TokenStream> = elements
                 .iter()
-                .map(|elem| {
-                    let pattern = match elem {
-                        TupleElement::Positional(pattern) => pattern,
-                        TupleElement::Indexed(boxed_elem) => &boxed_elem.pattern,
-                    };
-                    generate_pattern_nodes(pattern, node_defs, Some(&node_ident))
+                .enumerate()
+                .map(|(i, elem)| {
+                    let (pattern, step) = tuple_element_step(elem, i, positional);
+                    generate_pattern_nodes(pattern, node_defs, Some(&node_ident), step)
                 })
                 .collect();
 
@@ -228,6 +229,7 @@ pub(super) fn generate_pattern_nodes(
                         args: #args,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -238,12 +240,10 @@ pub(super) fn generate_pattern_nodes(
         Pattern::Tuple(PatternTuple { elements, .. }) => {
             let child_refs: Vec<TokenStream> = elements
                 .iter()
-                .map(|elem| {
-                    let pattern = match elem {
-                        TupleElement::Positional(pattern) => pattern,
-                        TupleElement::Indexed(boxed_elem) => &boxed_elem.pattern,
-                    };
-                    generate_pattern_nodes(pattern, node_defs, Some(&node_ident))
+                .enumerate()
+                .map(|(i, elem)| {
+                    let (pattern, step) = tuple_element_step(elem, i, true);
+                    generate_pattern_nodes(pattern, node_defs, Some(&node_ident), step)
                 })
                 .collect();
 
@@ -253,6 +253,7 @@ pub(super) fn generate_pattern_nodes(
                         items: &[#(&#child_refs),*],
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -261,14 +262,17 @@ pub(super) fn generate_pattern_nodes(
             }
         }
         Pattern::Slice(PatternSlice { elements, .. }) => {
-            let rest = elements
-                .iter()
-                .any(|e| matches!(e, Pattern::Range(PatternRange { node_id, .. }) if *node_id == usize::MAX));
+            // Rest elements are not meaningful for error reporting — they
+            // contribute only the `rest: bool` flag, not a child PatternNode.
+            let rest = elements.iter().any(PatternSlice::is_rest);
 
             let child_refs: Vec<TokenStream> = elements
                 .iter()
-                .filter(|e| !matches!(e, Pattern::Range(PatternRange { node_id, .. }) if *node_id == usize::MAX))
-                .map(|elem| generate_pattern_nodes(elem, node_defs, Some(&node_ident)))
+                .filter(|e| !PatternSlice::is_rest(e))
+                .map(|elem| {
+                    let step = path_step(quote!(Element));
+                    generate_pattern_nodes(elem, node_defs, Some(&node_ident), step)
+                })
                 .collect();
 
             quote! {
@@ -278,6 +282,7 @@ pub(super) fn generate_pattern_nodes(
                         rest: #rest,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -299,8 +304,10 @@ pub(super) fn generate_pattern_nodes(
                 .iter()
                 .map(|field| {
                     let field_name = field.operations.root_field_name().to_string();
+                    let field_path = field.operations.path_text();
+                    let step = path_step(quote!(Field(#field_path)));
                     let child_ref =
-                        generate_pattern_nodes(&field.pattern, node_defs, Some(&node_ident));
+                        generate_pattern_nodes(&field.pattern, node_defs, Some(&node_ident), step);
                     quote! {
                         (#field_name, &#child_ref)
                     }
@@ -315,6 +322,7 @@ pub(super) fn generate_pattern_nodes(
                         rest: #rest,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -323,9 +331,13 @@ pub(super) fn generate_pattern_nodes(
             }
         }
         Pattern::Set(PatternSet { elements, rest, .. }) => {
+            // Set items can match any element, so they add no step
             let child_refs: Vec<TokenStream> = elements
                 .iter()
-                .map(|elem| generate_pattern_nodes(elem, node_defs, Some(&node_ident)))
+                .map(|elem| {
+                    let step = path_step(quote!(Same));
+                    generate_pattern_nodes(elem, node_defs, Some(&node_ident), step)
+                })
                 .collect();
 
             quote! {
@@ -335,6 +347,7 @@ pub(super) fn generate_pattern_nodes(
                         rest: #rest,
                     },
                     parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -347,7 +360,9 @@ pub(super) fn generate_pattern_nodes(
                 .iter()
                 .map(|(key, value)| {
                     let key_str = quote! { #key }.to_string();
-                    let value_ref = generate_pattern_nodes(value, node_defs, Some(&node_ident));
+                    let step = path_step(quote!(Key(#key_str)));
+                    let value_ref =
+                        generate_pattern_nodes(value, node_defs, Some(&node_ident), step);
                     quote! {
                         (#key_str, &#value_ref)
                     }
@@ -361,6 +376,125 @@ pub(super) fn generate_pattern_nodes(
                         rest: #rest,
                     },
                     parent: #parent_ref,
+                    step: #step,
+                    line_start: #line_start,
+                    col_start: #col_start,
+                    line_end: #line_end,
+                    col_end: #col_end,
+                }
+            }
+        }
+        Pattern::Or(PatternOr { alternatives, .. }) => {
+            let child_refs: Vec<TokenStream> = alternatives
+                .iter()
+                .map(|alt| {
+                    generate_pattern_nodes(
+                        alt,
+                        node_defs,
+                        Some(&node_ident),
+                        path_step(quote!(Same)),
+                    )
+                })
+                .collect();
+
+            quote! {
+                ::assert_struct::__macro_support::PatternNode {
+                    kind: ::assert_struct::__macro_support::NodeKind::Or {
+                        alternatives: &[#(&#child_refs),*],
+                    },
+                    parent: #parent_ref,
+                    step: #step,
+                    line_start: #line_start,
+                    col_start: #col_start,
+                    line_end: #line_end,
+                    col_end: #col_end,
+                }
+            }
+        }
+        Pattern::Not(PatternNot { inner, .. }) => {
+            let inner_step = path_step(quote!(Same));
+            let inner_ref = generate_pattern_nodes(inner, node_defs, Some(&node_ident), inner_step);
+
+            quote! {
+                ::assert_struct::__macro_support::PatternNode {
+                    kind: ::assert_struct::__macro_support::NodeKind::Not {
+                        inner: &#inner_ref,
+                    },
+                    parent: #parent_ref,
+                    step: #step,
+                    line_start: #line_start,
+                    col_start: #col_start,
+                    line_end: #line_end,
+                    col_end: #col_end,
+                }
+            }
+        }
+        Pattern::And(PatternAnd { conjuncts, .. }) => {
+            let child_refs: Vec<TokenStream> = conjuncts
+                .iter()
+                .map(|conjunct| {
+                    let step = path_step(quote!(Same));
+                    generate_pattern_nodes(conjunct, node_defs, Some(&node_ident), step)
+                })
+                .collect();
+
+            quote! {
+                ::assert_struct::__macro_support::PatternNode {
+                    kind: ::assert_struct::__macro_support::NodeKind::And {
+                        conjuncts: &[#(&#child_refs),*],
+                    },
+                    parent: #parent_ref,
+                    step: #step,
+                    line_start: #line_start,
+                    col_start: #col_start,
+                    line_end: #line_end,
+                    col_end: #col_end,
+                }
+            }
+        }
+        Pattern::Approx(PatternApprox {
+            expected,
+            tolerance,
+            relative,
+            ..
+        }) => {
+            let expected_str = quote! { #expected }.to_string();
+            let tolerance_str = quote! { #tolerance }.to_string();
+            quote! {
+                ::assert_struct::__macro_support::PatternNode {
+                    kind: ::assert_struct::__macro_support::NodeKind::Approx {
+                        expected: #expected_str,
+                        tolerance: #tolerance_str,
+                        relative: #relative,
+                    },
+                    parent: #parent_ref,
+                    step: #step,
+                    line_start: #line_start,
+                    col_start: #col_start,
+                    line_end: #line_end,
+                    col_end: #col_end,
+                }
+            }
+        }
+        Pattern::Quantifier(PatternQuantifier {
+            quantifier, inner, ..
+        }) => {
+            let inner_step = path_step(quote!(Element));
+            let inner_ref = generate_pattern_nodes(inner, node_defs, Some(&node_ident), inner_step);
+            let kind = match quantifier {
+                Quantifier::All => quote!(::assert_struct::__macro_support::QuantifierKind::All),
+                Quantifier::Any => quote!(::assert_struct::__macro_support::QuantifierKind::Any),
+                Quantifier::None => quote!(::assert_struct::__macro_support::QuantifierKind::None),
+            };
+
+            quote! {
+                ::assert_struct::__macro_support::PatternNode {
+                    kind: ::assert_struct::__macro_support::NodeKind::Quantifier {
+                        kind: #kind,
+                        inner: &#inner_ref,
+                    },
+                    parent: #parent_ref,
+                    step: #step,
                     line_start: #line_start,
                     col_start: #col_start,
                     line_end: #line_end,
@@ -373,3 +507,22 @@ pub(super) fn generate_pattern_nodes(
     node_defs.push((node_id, node_def));
     quote! { #node_ident }
 }
+
+/// The pattern of a tuple or variant element and its path step: the field
+/// operations of an indexed element like `0.len(): 3`, else its position.
+fn tuple_element_step(
+    elem: &TupleElement,
+    position: usize,
+    positional: bool,
+) -> (&Pattern, TokenStream) {
+    match elem {
+        TupleElement::Positional(pattern) if positional => {
+            (pattern, path_step(quote!(Position(#position))))
+        }
+        TupleElement::Positional(pattern) => (pattern, path_step(quote!(Same))),
+        TupleElement::Indexed(boxed_elem) => {
+            let field_path = boxed_elem.operations.path_text();
+            (&boxed_elem.pattern, path_step(quote!(Field(#field_path))))
+        }
+    }
+}
@@ -32,6 +32,15 @@ use pattern::Pattern;
 struct AssertStruct {
     value: syn::Expr,
     pattern: Pattern,
+    // Optional trailing `format!` arguments for a custom failure message
+    message: Option<proc_macro2::TokenStream>,
+    // The whole macro input, embedded in the report for when the file can't be read
+    source: proc_macro2::TokenStream,
+}
+
+// Input of `pattern!`: a pattern without a value to match against
+struct StandalonePattern {
+    pattern: Pattern,
 }
 
 /// Structural assertion macro for testing complex data structures.
@@ -47,6 +56,7 @@ struct AssertStruct {
 ///
 /// ```text
 /// assert_struct!(expression, TypePattern);
+/// assert_struct!(expression, TypePattern, "format string", args...);
 ///
 /// TypePattern ::= TypeName '{' FieldPatternList '}'
 ///              | '{' FieldPatternList '}'            // Anonymous struct (always partial)
@@ -148,6 +158,9 @@ struct AssertStruct {
 ///   borrowed, not consumed, so the value remains available after the assertion.
 /// - **`TypeName`**: The struct type name. Must exactly match the runtime type of the expression.
 /// - **`{ fields }`**: Pattern specification for struct fields. Can be partial (with `..`) or exhaustive.
+/// - **`format string, args...`** (optional): Custom failure message, using the same syntax as
+///   `format!`. Like `assert_eq!`, the arguments are only evaluated when the assertion fails,
+///   and the message is appended to the report title.
 ///
 /// # Runtime Behavior
 ///
@@ -341,3 +354,121 @@ pub fn assert_struct(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Like [`assert_struct!`], but only enabled in builds with debug assertions.
+///
+/// This is the structural counterpart of `debug_assert!`, intended for internal
+/// invariants in non-test code. When `debug_assertions` are disabled (the
+/// default for release builds), the assertion is still type-checked but never
+/// runs: the value expression is not evaluated.
+///
+/// ```rust
+/// # use assert_struct::debug_assert_struct;
+/// # #[derive(Debug)]
+/// # struct Connection { retries: u32, open: bool }
+/// fn reconnect(conn: &mut Connection) {
+///     conn.retries += 1;
+///     conn.open = true;
+///     debug_assert_struct!(conn, Connection { retries: 1..=5, open: true });
+/// }
+/// # reconnect(&mut Connection { retries: 0, open: false });
+/// ```
+#[proc_macro]
+pub fn debug_assert_struct(input: TokenStream) -> TokenStream {
+    let assert = match syn::parse(input) {
+        Ok(assert) => assert,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    TokenStream::from(expand::expand_debug(&assert))
+}
+
+/// Non-panicking variant of [`assert_struct!`] that returns a `Result`.
+///
+/// Accepts exactly the same syntax as [`assert_struct!`] and runs the same
+/// assertion code, but evaluates to `Result<(), assert_struct::ErrorReport>`
+/// instead of panicking. The `Err` value renders the same annotated report
+/// through its `Display` impl and exposes the individual failures through
+/// `ErrorReport::failures()`.
+///
+/// ```rust
+/// # use assert_struct::check_struct;
+/// # #[derive(Debug)]
+/// # struct User { name: String, age: u32 }
+/// let user = User { name: "Alice".to_string(), age: 17 };
+///
+/// let result = check_struct!(user, User { name: "Alice", age: >= 18 });
+/// let report = result.unwrap_err();
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(report.failures().next().unwrap().actual(), "17");
+/// ```
+#[proc_macro]
+pub fn check_struct(input: TokenStream) -> TokenStream {
+    let assert = match syn::parse(input) {
+        Ok(assert) => assert,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    TokenStream::from(expand::expand_check(&assert))
+}
+
+/// Boolean variant of [`assert_struct!`]: returns whether the value matches.
+///
+/// Accepts the same syntax as [`assert_struct!`] but never panics and never
+/// renders a report, which makes it cheap enough for filters and predicates.
+///
+/// ```rust
+/// # use assert_struct::matches_struct;
+/// # #[derive(Debug)]
+/// # struct User { name: String, age: u32 }
+/// let user = User { name: "Alice".to_string(), age: 30 };
+///
+/// assert!(matches_struct!(user, User { age: >= 18, .. }));
+/// assert!(!matches_struct!(user, User { name: "Bob", .. }));
+/// ```
+#[proc_macro]
+pub fn matches_struct(input: TokenStream) -> TokenStream {
+    let assert: AssertStruct = match syn::parse(input) {
+        Ok(assert) => assert,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    if let Some(message) = &assert.message {
+        return TokenStream::from(
+            syn::Error::new_spanned(message, "matches_struct! does not accept a failure message")
+                .to_compile_error(),
+        );
+    }
+
+    TokenStream::from(expand::expand_matches(&assert))
+}
+
+/// Turn a pattern into a predicate closure implementing `Fn(&T) -> bool`.
+///
+/// The pattern uses the same syntax as the second argument of
+/// [`assert_struct!`]. The closure is intended for iterator adapters such as
+/// `filter`, `position` and `any`, where the parameter type is inferred from
+/// the call site.
+///
+/// ```rust
+/// # use assert_struct::pattern;
+/// # #[derive(Debug)]
+/// # struct Item { name: String, price: u32 }
+/// let items = vec![
+///     Item { name: "apple".to_string(), price: 3 },
+///     Item { name: "pear".to_string(), price: 0 },
+/// ];
+///
+/// assert!(items.iter().any(pattern!(Item { price: 0, .. })));
+/// assert_eq!(items.iter().position(pattern!({ name: "pear" })), Some(1));
+/// assert_eq!(items.iter().filter(pattern!({ price: > 0 })).count(), 1);
+/// ```
+#[proc_macro]
+pub fn pattern(input: TokenStream) -> TokenStream {
+    let pattern = match syn::parse(input) {
+        Ok(pattern) => pattern,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    TokenStream::from(expand::expand_predicate(&pattern))
+}
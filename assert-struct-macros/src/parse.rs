@@ -1,4 +1,5 @@
-use crate::AssertStruct;
+use crate::{AssertStruct, StandalonePattern};
+use proc_macro2::{Punct, Spacing, TokenTree};
 use std::cell::Cell;
 use syn::{Result, Token, parse::Parse, parse::ParseStream};
 
@@ -26,17 +27,137 @@ impl Parse for AssertStruct {
     /// assert_struct!(value, Pattern { field: matcher, .. })
     /// assert_struct!(value, Some(> 30))
     /// assert_struct!(value, [1, 2, 3])
+    /// assert_struct!(value, Pattern { .. }, "case {} failed", id)
     /// ```
     ///
-    /// The macro always expects: `expression`, `pattern`
+    /// The macro expects `expression`, `pattern`, optionally followed by
+    /// `format!`-style message arguments.
     fn parse(input: ParseStream) -> Result<Self> {
         // Reset the node ID counter for each macro invocation
         reset_node_counter();
 
+        // Keep the raw input so the report can embed it as source text
+        let source = input.fork().parse()?;
+
         let value = input.parse()?;
         let _: Token![,] = input.parse()?;
         let pattern = input.parse()?;
 
-        Ok(AssertStruct { value, pattern })
+        // Everything after the pattern is passed through to `format!` untouched
+        let mut message = None;
+        if input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+            if !input.is_empty() {
+                message = Some(input.parse()?);
+            }
+        }
+
+        Ok(AssertStruct {
+            value,
+            pattern,
+            message,
+            source,
+        })
+    }
+}
+
+impl Parse for StandalonePattern {
+    /// Parses the input of `pattern!`, which is a pattern with no value.
+    ///
+    /// # Example Input
+    /// ```text
+    /// pattern!(User { age: >= 18, .. })
+    /// pattern!(Some(> 0))
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        reset_node_counter();
+
+        let pattern = input.parse()?;
+
+        Ok(StandalonePattern { pattern })
     }
 }
+
+/// Parse the expression operand of a leaf pattern (`42`, `> limit`, `0..10`).
+///
+/// `syn::Expr` would happily consume `a | b` as a bitwise-or expression, so the
+/// operand is first cut off at the next top-level `,`, alternation `|` (but
+/// not `||`) or conjunction `&&`. Commas, pipes and `&&` inside delimiters and
+/// turbofish generics (`foo::<A, B>()`) belong to the operand. Bitwise-or and
+/// logical-and values can still be written by parenthesizing them:
+/// `== (A | B)`, `== (a && b)`.
+pub(crate) fn parse_operand(input: ParseStream) -> Result<syn::Expr> {
+    let tokens = scan_operand(input, |_, _| false)?;
+    syn::parse2(tokens.into_iter().collect())
+}
+
+/// Collect the tokens of an operand as described on [`parse_operand`], also
+/// stopping at any top-level punct for which `stop(punct, next_char)` is true.
+pub(crate) fn scan_operand(
+    input: ParseStream,
+    stop: impl Fn(&Punct, Option<char>) -> bool,
+) -> Result<Vec<TokenTree>> {
+    let tokens = input.step(|cursor| {
+        let mut rest = *cursor;
+        let mut tokens = Vec::new();
+        let mut angle_depth = 0usize;
+        // The previous token, if it was a punct joined to the current one
+        let mut joined_to: Option<char> = None;
+        let mut after_path_sep = false;
+        // Whether the previous token ends an operand, so `&&` is binary
+        let mut after_operand = false;
+
+        while let Some((tt, next)) = rest.token_tree() {
+            let mut joins = None;
+            let mut is_path_sep = false;
+
+            if let TokenTree::Punct(punct) = &tt {
+                let ch = punct.as_char();
+                let joint = punct.spacing() == Spacing::Joint;
+                let next_char = next.punct().map(|(p, _)| p.as_char());
+
+                if angle_depth == 0 && !tokens.is_empty() && stop(punct, next_char) {
+                    break;
+                }
+
+                match ch {
+                    ',' if angle_depth == 0 => break,
+                    // A lone `|` separates alternatives; `||` is logical or
+                    '|' if angle_depth == 0
+                        && !tokens.is_empty()
+                        && joined_to != Some('|')
+                        && !(joint && next_char == Some('|')) =>
+                    {
+                        break;
+                    }
+                    // A binary `&&` separates conjuncts; a leading one is a double reference
+                    '&' if angle_depth == 0 && after_operand && joint && next_char == Some('&') => {
+                        break;
+                    }
+                    '<' if after_path_sep || angle_depth > 0 => angle_depth += 1,
+                    '>' if angle_depth > 0 && joined_to != Some('-') => angle_depth -= 1,
+                    ':' => is_path_sep = joined_to == Some(':'),
+                    _ => {}
+                }
+
+                if joint {
+                    joins = Some(ch);
+                }
+            }
+
+            after_operand = !matches!(tt, TokenTree::Punct(_));
+            tokens.push(tt);
+            joined_to = joins;
+            after_path_sep = is_path_sep;
+            rest = next;
+        }
+
+        Ok((tokens, rest))
+    })?;
+
+    if tokens.is_empty() {
+        return Err(input.error("expected an expression"));
+    }
+
+    Ok(tokens)
+}
@@ -3,11 +3,16 @@
 //! This module defines the various pattern types that can be used in assertions,
 //! along with helper types for field operations and tuple elements.
 
+mod and;
+mod approx;
 mod closure;
 mod comparison;
 mod enum_pattern;
 mod field;
 mod map;
+mod not;
+mod or;
+mod quantifier;
 mod range;
 mod set;
 mod simple;
@@ -21,11 +26,16 @@ mod wildcard;
 mod regex;
 
 // Re-export all pattern types
+pub(crate) use and::PatternAnd;
+pub(crate) use approx::PatternApprox;
 pub(crate) use closure::PatternClosure;
 pub(crate) use comparison::{ComparisonOp, PatternComparison};
 pub(crate) use enum_pattern::PatternEnum;
 pub(crate) use field::{FieldAssertion, FieldOperation};
 pub(crate) use map::PatternMap;
+pub(crate) use not::PatternNot;
+pub(crate) use or::PatternOr;
+pub(crate) use quantifier::{PatternQuantifier, Quantifier};
 pub(crate) use range::PatternRange;
 pub(crate) use set::PatternSet;
 pub(crate) use simple::PatternSimple;
@@ -38,6 +48,7 @@ pub(crate) use wildcard::PatternWildcard;
 #[cfg(feature = "regex")]
 pub(crate) use regex::{PatternLike, PatternRegex};
 
+use crate::parse::next_node_id;
 use proc_macro2::Span;
 use syn::{
     Token,
@@ -64,9 +75,40 @@ pub(crate) enum Pattern {
     Wildcard(PatternWildcard),
     Closure(PatternClosure),
     Map(PatternMap),
+    Or(PatternOr),
+    Not(PatternNot),
+    And(PatternAnd),
+    Approx(PatternApprox),
+    Quantifier(PatternQuantifier),
 }
 
 impl Pattern {
+    /// The ID of this pattern's node, named `__PATTERN_NODE_{id}` in generated code.
+    pub(crate) fn node_id(&self) -> usize {
+        match self {
+            Pattern::Simple(PatternSimple { node_id, .. })
+            | Pattern::String(PatternString { node_id, .. })
+            | Pattern::Struct(PatternStruct { node_id, .. })
+            | Pattern::Enum(PatternEnum { node_id, .. })
+            | Pattern::Tuple(PatternTuple { node_id, .. })
+            | Pattern::Slice(PatternSlice { node_id, .. })
+            | Pattern::Comparison(PatternComparison { node_id, .. })
+            | Pattern::Range(PatternRange { node_id, .. })
+            | Pattern::Wildcard(PatternWildcard { node_id })
+            | Pattern::Closure(PatternClosure { node_id, .. })
+            | Pattern::Map(PatternMap { node_id, .. })
+            | Pattern::Set(PatternSet { node_id, .. })
+            | Pattern::Or(PatternOr { node_id, .. })
+            | Pattern::Not(PatternNot { node_id, .. })
+            | Pattern::And(PatternAnd { node_id, .. })
+            | Pattern::Approx(PatternApprox { node_id, .. })
+            | Pattern::Quantifier(PatternQuantifier { node_id, .. }) => *node_id,
+            #[cfg(feature = "regex")]
+            Pattern::Regex(PatternRegex { node_id, .. })
+            | Pattern::Like(PatternLike { node_id, .. }) => *node_id,
+        }
+    }
+
     pub(crate) fn span(&self) -> Option<Span> {
         match self {
             Pattern::Simple(PatternSimple { expr, .. }) => Some(expr.span()),
@@ -86,8 +128,16 @@ impl Pattern {
             | Pattern::Slice(PatternSlice { .. })
             | Pattern::Set(PatternSet { .. })
             | Pattern::Wildcard(PatternWildcard { .. })
-            | Pattern::Map(PatternMap { .. }) => None,
+            | Pattern::Map(PatternMap { .. })
+            | Pattern::Quantifier(PatternQuantifier { .. }) => None,
             Pattern::Closure(PatternClosure { closure, .. }) => Some(closure.span()),
+            Pattern::Or(PatternOr { alternatives, .. }) => {
+                alternatives.first().and_then(Pattern::span)
+            }
+            Pattern::Not(PatternNot { span, .. }) | Pattern::Approx(PatternApprox { span, .. }) => {
+                Some(*span)
+            }
+            Pattern::And(PatternAnd { conjuncts, .. }) => conjuncts.first().and_then(Pattern::span),
         }
     }
 
@@ -216,7 +266,8 @@ impl Pattern {
             Pattern::Tuple(PatternTuple { span, .. })
             | Pattern::Slice(PatternSlice { span, .. })
             | Pattern::Set(PatternSet { span, .. })
-            | Pattern::Map(PatternMap { span, .. }) => {
+            | Pattern::Map(PatternMap { span, .. })
+            | Pattern::Quantifier(PatternQuantifier { span, .. }) => {
                 let start = span.start();
                 let end = span.end();
                 (
@@ -226,10 +277,66 @@ impl Pattern {
                     end.column as u32,
                 )
             }
-            // Wildcard struct patterns and plain wildcards have no meaningful location.
-            Pattern::Struct(PatternStruct { path: None, .. }) | Pattern::Wildcard(_) => {
-                (0, 0, 0, 0)
+            // Span from the first part to the last one that has a location.
+            Pattern::Or(PatternOr {
+                alternatives: parts,
+                ..
+            })
+            | Pattern::And(PatternAnd {
+                conjuncts: parts, ..
+            }) => {
+                let located: Vec<_> = parts
+                    .iter()
+                    .map(Pattern::location)
+                    .filter(|(line_start, ..)| *line_start != 0)
+                    .collect();
+                match (located.first(), located.last()) {
+                    (Some(&(line_start, col_start, ..)), Some(&(.., line_end, col_end))) => {
+                        (line_start, col_start, line_end, col_end)
+                    }
+                    _ => (0, 0, 0, 0),
+                }
+            }
+            // From the `!` or `not` to the closing paren or the end of the inner pattern.
+            Pattern::Not(PatternNot {
+                span, close, inner, ..
+            }) => {
+                let start = span.start();
+                let (line_end, col_end) = match close {
+                    Some(close) => (close.end().line as u32, close.end().column as u32),
+                    None => match inner.location() {
+                        (0, ..) => (span.end().line as u32, span.end().column as u32),
+                        (.., line_end, col_end) => (line_end, col_end),
+                    },
+                };
+                (start.line as u32, start.column as u32, line_end, col_end)
+            }
+            // From the `~` to the end of the tolerance.
+            Pattern::Approx(PatternApprox { span, end, .. }) => {
+                let start = span.start();
+                let end = end.end();
+                (
+                    start.line as u32,
+                    start.column as u32,
+                    end.line as u32,
+                    end.column as u32,
+                )
+            }
+            // Anonymous struct patterns have no path, so highlight the braces.
+            Pattern::Struct(PatternStruct {
+                path: None, braces, ..
+            }) => {
+                let start = braces.start();
+                let end = braces.end();
+                (
+                    start.line as u32,
+                    start.column as u32,
+                    end.line as u32,
+                    end.column as u32,
+                )
             }
+            // Plain wildcards have no meaningful location.
+            Pattern::Wildcard(_) => (0, 0, 0, 0),
         }
     }
 }
@@ -237,9 +344,58 @@ impl Pattern {
 impl Parse for Pattern {
     /// Parse any pattern at any level - the heart of the macro's flexibility.
     ///
+    /// A pattern is one or more alternatives separated by `|`, each of which is
+    /// one or more conjuncts separated by `&&`. Each conjunct is parsed by
+    /// [`Pattern::parse_primary`].
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let first = Pattern::parse_conjunction(input)?;
+
+        // Alternation: `200 | 204`, `Status::Active | Status::Pending { .. }`.
+        if !peek_alternation(input) {
+            return Ok(first);
+        }
+
+        let mut alternatives = vec![first];
+        while peek_alternation(input) {
+            let _: Token![|] = input.parse()?;
+            alternatives.push(Pattern::parse_conjunction(input)?);
+        }
+
+        Ok(Pattern::Or(PatternOr {
+            node_id: next_node_id(),
+            alternatives,
+        }))
+    }
+}
+
+impl Pattern {
+    /// Parse one alternative: a single pattern or a conjunction of patterns.
+    ///
+    /// Example: `=~ r"^id-" && != "id-0"`, `> 0 && < 100`
+    fn parse_conjunction(input: ParseStream) -> syn::Result<Self> {
+        let first = Pattern::parse_primary(input)?;
+
+        if !input.peek(Token![&&]) {
+            return Ok(first);
+        }
+
+        let mut conjuncts = vec![first];
+        while input.peek(Token![&&]) {
+            let _: Token![&&] = input.parse()?;
+            conjuncts.push(Pattern::parse_primary(input)?);
+        }
+
+        Ok(Pattern::And(PatternAnd {
+            node_id: next_node_id(),
+            conjuncts,
+        }))
+    }
+
+    /// Parse a single pattern without alternation or conjunction.
+    ///
     /// This handles all pattern types in a specific order to avoid ambiguity.
     /// The order matters because some patterns share prefixes.
-    fn parse(input: ParseStream) -> syn::Result<Self> {
+    fn parse_primary(input: ParseStream) -> syn::Result<Self> {
         // Closure pattern: |x| expr or move |x| expr for custom validation (escape hatch)
         // Examples: `|x| x > 5`, `move |x| complex_logic(x)`, `|x| { x.len() > 0 }`
         if input.peek(Token![|]) || (input.peek(Token![move]) && input.peek2(Token![|])) {
@@ -259,12 +415,36 @@ impl Parse for Pattern {
             }
         }
 
+        // `!=~` is a negated `=~`, not `!=` followed by `~`
+        let negated_like =
+            input.peek(Token![!]) && input.peek2(Token![=]) && input.peek3(Token![~]);
+
         // Try to parse as a comparison pattern (<, <=, >, >=, ==, !=)
-        if input.peek(Token![<]) || input.peek(Token![>]) || input.peek(Token![!]) {
+        if input.peek(Token![<])
+            || input.peek(Token![>])
+            || (input.peek(Token![!=]) && !negated_like)
+        {
             // These always start comparisons, safe to parse directly
             return Ok(Pattern::Comparison(input.parse()?));
         }
 
+        // Negation: `!pattern`, `!(pattern)` or `not(pattern)`
+        // Example: `!Status::Active`, `!(1 | 2)`, `not(=~ r"^tmp-")`
+        if input.peek(Token![!]) {
+            return Ok(Pattern::Not(input.parse()?));
+        }
+        if input.peek(syn::Ident) && input.peek2(syn::token::Paren) {
+            let fork = input.fork();
+            if fork.parse::<syn::Ident>().is_ok_and(|ident| ident == "not") {
+                return Ok(Pattern::Not(input.parse()?));
+            }
+        }
+
+        // Approximate equality for floats: `~ 0.3 +- 1e-9`, `~ 0.3 +- 0.1%`
+        if input.peek(Token![~]) {
+            return Ok(Pattern::Approx(input.parse()?));
+        }
+
         // `=` could start `==` (equality) or `=~` (regex pattern)
         if input.peek(Token![=]) {
             if input.peek2(Token![=]) {
@@ -294,6 +474,12 @@ impl Parse for Pattern {
             return Ok(Pattern::Map(input.parse()?));
         }
 
+        // Quantifier patterns apply one pattern to every element
+        // Example: `[all: > 0]`, `[any: "admin"]`, `[none: None]`
+        if PatternQuantifier::peek(input) {
+            return Ok(Pattern::Quantifier(input.parse()?));
+        }
+
         // Slice patterns for Vec/array matching
         // Example: `[1, 2, 3]` or `[> 0, < 10, == 5]`
         if input.peek(syn::token::Bracket) {
@@ -343,3 +529,29 @@ impl Parse for Pattern {
         }
     }
 }
+
+/// Whether the next token separates alternatives. `||` never does.
+fn peek_alternation(input: ParseStream) -> bool {
+    input.peek(Token![|]) && !input.peek(Token![||])
+}
+
+/// Render tokens roughly as they were written, e.g. `data.items[0]` rather
+/// than the `data . items [0]` that `TokenStream::to_string` produces.
+pub(crate) fn source_text(tokens: &proc_macro2::TokenStream) -> String {
+    let text = tokens.to_string();
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ' ' {
+            let before = i.checked_sub(1).map(|j| chars[j]);
+            let after = chars.get(i + 1).copied();
+            if before.is_some_and(|b| ".:([&*!".contains(b))
+                || after.is_some_and(|a| ".:()[],?".contains(a))
+            {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
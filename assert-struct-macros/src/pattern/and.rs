@@ -0,0 +1,16 @@
+//! Conjunction pattern types.
+//!
+//! Handles conjunction patterns: =~ r"^id-" && != "id-0", > 0 && < 100
+
+use crate::pattern::Pattern;
+
+/// Conjunction pattern: pat1 && pat2 && ... - matches if every conjunct matches.
+///
+/// Parsed by `Pattern::parse`, which splits on top-level `&&`. Conjuncts bind
+/// tighter than alternatives, so `a && b | c` is `(a && b) | c`. Always holds
+/// at least two conjuncts, each of which reports its own failure.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternAnd {
+    pub node_id: usize,
+    pub conjuncts: Vec<Pattern>,
+}
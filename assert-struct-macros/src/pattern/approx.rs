@@ -0,0 +1,69 @@
+//! Approximate equality pattern types.
+//!
+//! Handles tolerance patterns for floats: ~ 0.3 +- 1e-9, ~ 0.3 +- 0.1%
+
+use proc_macro2::{Span, TokenTree};
+use syn::{Token, parse::Parse, spanned::Spanned};
+
+use crate::parse::{next_node_id, scan_operand};
+
+/// Approximate equality pattern: ~ expected +- tolerance, with the tolerance
+/// either absolute or, with a trailing `%`, relative to `expected`.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternApprox {
+    pub node_id: usize,
+    /// Span of the `~` token.
+    pub span: Span,
+    pub expected: syn::Expr,
+    pub tolerance: Box<syn::Expr>,
+    /// Whether the tolerance is a percentage of `expected`.
+    pub relative: bool,
+    /// Span of the last token of the pattern.
+    pub end: Span,
+}
+
+impl Parse for PatternApprox {
+    /// Parses an approximate equality pattern.
+    ///
+    /// # Example Input
+    /// ```text
+    /// ~ 0.3 +- 1e-9
+    /// ~ expected_ratio +- 0.1%
+    /// ```
+    ///
+    /// `±` is not a Rust token, so the tolerance is introduced by `+-`.
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let tilde: Token![~] = input.parse()?;
+
+        // The expected value runs up to `+-`, which would otherwise parse as `+ -tolerance`
+        let expected = scan_operand(input, |punct, next| {
+            punct.as_char() == '+' && next == Some('-')
+        })?;
+        let expected: syn::Expr = syn::parse2(expected.into_iter().collect())?;
+
+        if !(input.peek(Token![+]) && input.peek2(Token![-])) {
+            return Err(input.error("expected `+-` followed by a tolerance, e.g. `~ 0.3 +- 1e-9`"));
+        }
+        let _: Token![+] = input.parse()?;
+        let _: Token![-] = input.parse()?;
+
+        let mut tolerance = scan_operand(input, |_, _| false)?;
+        let end = tolerance
+            .last()
+            .map_or_else(|| tilde.span(), TokenTree::span);
+        let relative = matches!(tolerance.last(), Some(TokenTree::Punct(p)) if p.as_char() == '%');
+        if relative {
+            tolerance.pop();
+        }
+        let tolerance: syn::Expr = syn::parse2(tolerance.into_iter().collect())?;
+
+        Ok(PatternApprox {
+            node_id: next_node_id(),
+            span: tilde.span(),
+            expected,
+            tolerance: Box::new(tolerance),
+            relative,
+            end,
+        })
+    }
+}
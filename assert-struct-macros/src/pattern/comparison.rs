@@ -5,7 +5,7 @@
 use proc_macro2::Span;
 use syn::{Token, parse::Parse, spanned::Spanned};
 
-use crate::parse::next_node_id;
+use crate::parse::{next_node_id, parse_operand};
 
 /// Comparison pattern: > 30, <= 100
 #[derive(Debug, Clone)]
@@ -63,7 +63,7 @@ impl Parse for ComparisonOp {
 impl Parse for PatternComparison {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let op: ComparisonOp = input.parse()?;
-        let expr: syn::Expr = input.parse()?;
+        let expr = parse_operand(input)?;
         Ok(PatternComparison {
             node_id: next_node_id(),
             op,
@@ -5,7 +5,7 @@
 use std::fmt;
 use syn::{Token, parse::Parse};
 
-use crate::pattern::Pattern;
+use crate::pattern::{Pattern, source_text};
 
 /// Represents a field name which can be either an identifier (for structs)
 /// or an index (for tuples)
@@ -278,6 +278,30 @@ impl FieldOperation {
 }
 
 impl FieldOperation {
+    /// The field path this operation adds, e.g. `.items[0].len()`. Derefs are
+    /// left out, as they don't change where the value lives.
+    pub(crate) fn path_text(&self) -> String {
+        match self {
+            FieldOperation::Deref { .. } => String::new(),
+            FieldOperation::Method { name, args, .. } => {
+                let args: Vec<String> = args
+                    .iter()
+                    .map(|arg| source_text(&quote::quote!(#arg)))
+                    .collect();
+                format!(".{}({})", name, args.join(", "))
+            }
+            FieldOperation::Await { .. } => ".await".to_string(),
+            FieldOperation::NamedField { name, .. } => format!(".{}", name),
+            FieldOperation::UnnamedField { index, .. } => format!(".{}", index),
+            FieldOperation::Index { index, .. } => {
+                format!("[{}]", source_text(&quote::quote!(#index)))
+            }
+            FieldOperation::Chained { operations, .. } => {
+                operations.iter().map(FieldOperation::path_text).collect()
+            }
+        }
+    }
+
     /// Parse a dot operation: .await, .field, .method(), or .0
     /// Pushes the parsed operation(s) into the provided Vec
     fn parse_one_dot_into(
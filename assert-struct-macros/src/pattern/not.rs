@@ -0,0 +1,67 @@
+//! Negation pattern types.
+//!
+//! Handles negation patterns: !Status::Active, !(1 | 2), not(=~ r"^tmp-")
+
+use proc_macro2::Span;
+use syn::{Token, parse::Parse};
+
+use crate::parse::next_node_id;
+use crate::pattern::Pattern;
+
+/// Negation pattern: !pattern, !(pattern) or not(pattern) - matches if the
+/// inner pattern does not.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternNot {
+    pub node_id: usize,
+    /// Span of the `!` or `not` token.
+    pub span: Span,
+    /// Span of the closing parenthesis, for the parenthesized forms.
+    pub close: Option<Span>,
+    pub inner: Box<Pattern>,
+}
+
+impl Parse for PatternNot {
+    /// Parses a negation pattern.
+    ///
+    /// # Example Input
+    /// ```text
+    /// !Status::Active
+    /// !(200 | 204)
+    /// not(Some(_))
+    /// ```
+    ///
+    /// Without parentheses, `!` applies to a single alternative, so
+    /// `!A | B` means `(!A) | B`. Parentheses directly after `!` always group,
+    /// so a negated tuple needs a second pair: `!((1, 2))`.
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = if input.peek(Token![!]) {
+            let bang: Token![!] = input.parse()?;
+            bang.span
+        } else {
+            let ident: syn::Ident = input.parse()?;
+            if ident != "not" || !input.peek(syn::token::Paren) {
+                return Err(syn::Error::new(ident.span(), "expected `!` or `not(...)`"));
+            }
+            ident.span()
+        };
+
+        let (inner, close) = if input.peek(syn::token::Paren) {
+            let content;
+            let paren = syn::parenthesized!(content in input);
+            let inner: Pattern = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("expected `)` after negated pattern"));
+            }
+            (inner, Some(paren.span.close()))
+        } else {
+            (Pattern::parse_primary(input)?, None)
+        };
+
+        Ok(PatternNot {
+            node_id: next_node_id(),
+            span,
+            close,
+            inner: Box::new(inner),
+        })
+    }
+}
@@ -0,0 +1,15 @@
+//! Alternation pattern types.
+//!
+//! Handles alternation patterns: 200 | 204, Status::Active | Status::Pending { .. }
+
+use crate::pattern::Pattern;
+
+/// Alternation pattern: pat1 | pat2 | ... - matches if any alternative matches.
+///
+/// Parsed by `Pattern::parse`, which splits on top-level `|` after each
+/// alternative. Always holds at least two alternatives.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternOr {
+    pub node_id: usize,
+    pub alternatives: Vec<Pattern>,
+}
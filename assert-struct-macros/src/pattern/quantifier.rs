@@ -0,0 +1,92 @@
+//! Quantifier pattern types.
+//!
+//! Handles quantifier patterns over iterables: `[all: > 0]`, `[any: "admin"]`, `[none: None]`
+
+use proc_macro2::{Delimiter, Spacing, Span};
+use syn::{Token, parse::Parse};
+
+use crate::parse::next_node_id;
+use crate::pattern::Pattern;
+
+/// Quantifier pattern: [all: pattern], [any: pattern] or [none: pattern] -
+/// applies one pattern to every element of an iterable of any length.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternQuantifier {
+    pub node_id: usize,
+    pub span: Span,
+    pub quantifier: Quantifier,
+    pub inner: Box<Pattern>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Quantifier {
+    /// Every element must match
+    All,
+    /// At least one element must match
+    Any,
+    /// No element may match
+    None,
+}
+
+impl PatternQuantifier {
+    /// Whether the bracketed input starts with a quantifier keyword, i.e.
+    /// `all:`, `any:` or `none:` (but not a path like `all::X`).
+    pub(crate) fn peek(input: syn::parse::ParseStream) -> bool {
+        let Some((content, _, _)) = input.cursor().group(Delimiter::Bracket) else {
+            return false;
+        };
+        let Some((ident, rest)) = content.ident() else {
+            return false;
+        };
+        let is_keyword = ident == "all" || ident == "any" || ident == "none";
+        is_keyword
+            && matches!(
+                rest.punct(),
+                Some((colon, _)) if colon.as_char() == ':' && colon.spacing() == Spacing::Alone
+            )
+    }
+}
+
+impl Parse for PatternQuantifier {
+    /// Parses a quantifier pattern.
+    ///
+    /// # Example Input
+    /// ```text
+    /// [all: Item { price: > 0, .. }]
+    /// [any: "admin"]
+    /// [none: == 0]
+    /// ```
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        let bracket = syn::bracketed!(content in input);
+        // Span of the whole `[...]` group
+        let span = bracket.span.join();
+
+        let keyword: syn::Ident = content.parse()?;
+        let quantifier = if keyword == "all" {
+            Quantifier::All
+        } else if keyword == "any" {
+            Quantifier::Any
+        } else if keyword == "none" {
+            Quantifier::None
+        } else {
+            return Err(syn::Error::new(
+                keyword.span(),
+                "expected `all`, `any` or `none`",
+            ));
+        };
+        let _: Token![:] = content.parse()?;
+
+        let inner: Pattern = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("a quantifier takes a single pattern, e.g. `[all: > 0]`"));
+        }
+
+        Ok(PatternQuantifier {
+            node_id: next_node_id(),
+            span,
+            quantifier,
+            inner: Box::new(inner),
+        })
+    }
+}
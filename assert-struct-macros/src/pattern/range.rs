@@ -4,7 +4,7 @@
 
 use syn::parse::Parse;
 
-use crate::parse::next_node_id;
+use crate::parse::{next_node_id, parse_operand};
 
 /// Range pattern: 10..20, 0..=100
 #[derive(Debug, Clone)]
@@ -26,7 +26,7 @@ impl Parse for PatternRange {
     ///
     /// This parses any valid Rust range expression.
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let expr: syn::Expr = input.parse()?;
+        let expr = parse_operand(input)?;
 
         // Verify this is actually a range expression
         if !matches!(expr, syn::Expr::Range(_)) {
@@ -4,7 +4,7 @@
 
 use syn::{Token, parse::Parse};
 
-use crate::parse::next_node_id;
+use crate::parse::{next_node_id, parse_operand};
 
 /// Regex pattern: =~ "pattern" - string literal optimized at compile time
 #[cfg(feature = "regex")]
@@ -64,7 +64,7 @@ impl Parse for PatternLike {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let _: Token![=] = input.parse()?;
         let _: Token![~] = input.parse()?;
-        let expr = input.parse::<syn::Expr>()?;
+        let expr = parse_operand(input)?;
 
         Ok(PatternLike {
             node_id: next_node_id(),
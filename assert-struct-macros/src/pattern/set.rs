@@ -10,7 +10,7 @@ use crate::pattern::Pattern;
 /// Set pattern: #(pattern, ..) for unordered collection matching.
 ///
 /// Each element pattern must match a distinct element of the collection,
-/// in any order. Matching uses backtracking to find a valid assignment.
+/// in any order. Matching uses maximum bipartite matching to find a valid assignment.
 #[derive(Debug, Clone)]
 pub(crate) struct PatternSet {
     pub node_id: usize,
@@ -4,7 +4,7 @@
 
 use syn::parse::Parse;
 
-use crate::parse::next_node_id;
+use crate::parse::{next_node_id, parse_operand};
 
 /// Simple value pattern: 42, "hello", true
 #[derive(Debug, Clone)]
@@ -27,7 +27,7 @@ impl Parse for PatternSimple {
     ///
     /// This parses any valid Rust expression except ranges (handled by PatternRange).
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let expr = input.parse::<syn::Expr>()?;
+        let expr = parse_operand(input)?;
 
         Ok(PatternSimple {
             node_id: next_node_id(),
@@ -5,7 +5,7 @@
 use syn::{Token, parse::Parse};
 
 use crate::parse::next_node_id;
-use crate::pattern::Pattern;
+use crate::pattern::{Pattern, PatternRange};
 
 /// Slice pattern: [1, 2, 3] or [1, .., 5]
 #[derive(Debug, Clone)]
@@ -15,6 +15,19 @@ pub(crate) struct PatternSlice {
     pub elements: Vec<Pattern>,
 }
 
+impl PatternSlice {
+    /// Whether a slice element is the rest pattern `..`, which parses as a full range.
+    pub fn is_rest(pattern: &Pattern) -> bool {
+        matches!(
+            pattern,
+            Pattern::Range(PatternRange {
+                expr: syn::Expr::Range(r),
+                ..
+            }) if r.start.is_none() && r.end.is_none()
+        )
+    }
+}
+
 impl Parse for PatternSlice {
     /// Parses a slice pattern: [pattern, pattern, ...]
     ///
@@ -15,6 +15,8 @@ pub(crate) struct PatternStruct {
     pub path: Option<syn::Path>,
     pub fields: Punctuated<FieldAssertion, Token![,]>,
     pub rest: bool,
+    /// The braces and everything between them, used to locate anonymous structs
+    pub braces: proc_macro2::Span,
 }
 
 impl Parse for PatternStruct {
@@ -43,7 +45,7 @@ impl Parse for PatternStruct {
 
         // Parse the braced contents
         let content;
-        syn::braced!(content in input);
+        let brace = syn::braced!(content in input);
 
         // Parse comma-separated field assertions with optional rest pattern (..)
         let mut fields = Punctuated::new();
@@ -85,6 +87,7 @@ impl Parse for PatternStruct {
             path,
             fields,
             rest,
+            braces: brace.span.join(),
         })
     }
 }
@@ -0,0 +1,520 @@
+//! Diffs between expected and actual values, shown below the source snippet.
+//!
+//! Two kinds are produced:
+//! - structural diffs of `{:#?}` renderings, keeping only the changed lines and
+//!   the blocks enclosing them
+//! - text diffs of strings, character-level for a single line and a unified
+//!   line diff for multiline text
+//!
+//! Diffs are rendered when the report is displayed, so they can follow the
+//! same plain/styled choice as the rest of the report.
+
+use std::fmt::Write as _;
+use std::ops::Range;
+
+use annotate_snippets::renderer::{
+    DEFAULT_ADDITION_STYLE, DEFAULT_CONTEXT_STYLE, DEFAULT_REMOVAL_STYLE, Effects, Style,
+};
+
+/// Above this many cells the matching table is not built and the differing
+/// middle is treated as a whole removal and insertion.
+const MAX_TABLE_CELLS: usize = 1 << 22;
+
+/// Single-line strings shorter than this (in characters) are left to the
+/// label, which already shows them in full.
+const SHORT_TEXT: usize = 40;
+
+/// Most separate differing runs highlighted on one side of a line.
+const MAX_HIGHLIGHTS: usize = 4;
+
+/// Unchanged lines kept around each change in a unified text diff.
+const CONTEXT_LINES: usize = 2;
+
+/// A rendered-on-demand diff attached to a failure.
+#[derive(Debug, Clone)]
+pub(crate) struct Diff {
+    lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone)]
+struct DiffLine {
+    kind: LineKind,
+    text: String,
+    /// Byte ranges of `text` that differ from the other side
+    highlights: Vec<Range<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Same,
+    Removed,
+    Added,
+    /// A run of unchanged lines that was left out
+    Skipped,
+    /// A unified diff hunk header, e.g. `@@ -3,4 +3,5 @@`
+    Hunk,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+impl Diff {
+    /// Render the diff, coloring removals and additions when `styled`.
+    ///
+    /// Without color, the differing characters of a line are pointed out with
+    /// `^` on the line below it.
+    pub(crate) fn render(&self, styled: bool) -> String {
+        let mut out = String::new();
+        for line in &self.lines {
+            let (marker, style) = match line.kind {
+                LineKind::Same | LineKind::Skipped => (' ', Style::new()),
+                LineKind::Removed => ('-', DEFAULT_REMOVAL_STYLE),
+                LineKind::Added => ('+', DEFAULT_ADDITION_STYLE),
+                LineKind::Hunk => {
+                    if styled {
+                        let style = DEFAULT_CONTEXT_STYLE;
+                        let _ = writeln!(out, "{style}{}{style:#}", line.text);
+                    } else {
+                        let _ = writeln!(out, "{}", line.text);
+                    }
+                    continue;
+                }
+            };
+
+            if !styled {
+                let _ = writeln!(out, "{} {}", marker, line.text);
+                if !line.highlights.is_empty() {
+                    let _ = writeln!(out, "  {}", carets(&line.text, &line.highlights));
+                }
+                continue;
+            }
+
+            let strong = style.effects(Effects::BOLD | Effects::UNDERLINE);
+            let _ = write!(out, "{style}{marker} ");
+            let mut pos = 0;
+            for range in &line.highlights {
+                let _ = write!(
+                    out,
+                    "{}{strong}{}{strong:#}{style}",
+                    &line.text[pos..range.start],
+                    &line.text[range.clone()],
+                );
+                pos = range.end;
+            }
+            let _ = writeln!(out, "{}{style:#}", &line.text[pos..]);
+        }
+        out.pop();
+        out
+    }
+}
+
+/// Diff two `{:#?}` renderings, keeping only the changed lines and the lines
+/// that open the structs, collections and variants enclosing them.
+///
+/// Returns `None` when the renderings are equal or both fit on a single line,
+/// since the label already shows those in full.
+pub(crate) fn structural_diff(expected: &str, actual: &str) -> Option<Diff> {
+    if expected == actual || (!expected.contains('\n') && !actual.contains('\n')) {
+        return None;
+    }
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let ops = diff_sequences(&expected, &actual);
+
+    // Unchanged lines that open a block, innermost last, as indices into `ops`
+    let mut open_blocks: Vec<usize> = Vec::new();
+    let mut shown = vec![false; ops.len()];
+
+    for (i, op) in ops.iter().enumerate() {
+        match *op {
+            Op::Same(text) => {
+                let indent = indent_of(text);
+                while open_blocks
+                    .last()
+                    .is_some_and(|&open| indent_of(op_text(ops[open])) >= indent)
+                {
+                    open_blocks.pop();
+                }
+                if opens_block(text) {
+                    open_blocks.push(i);
+                }
+            }
+            Op::Removed(_) | Op::Added(_) => {
+                for &open in &open_blocks {
+                    shown[open] = true;
+                }
+                shown[i] = true;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut last_shown = None;
+    for (i, op) in ops.iter().enumerate() {
+        if !shown[i] {
+            continue;
+        }
+        let skipped = match last_shown {
+            Some(last) => i > last + 1,
+            None => i > 0,
+        };
+        if skipped {
+            lines.push(skipped_line(indent_of(op_text(*op))));
+        }
+        lines.push(line_for(*op));
+        last_shown = Some(i);
+    }
+    if last_shown.is_some_and(|last| last + 1 < ops.len()) {
+        lines.push(skipped_line(0));
+    }
+
+    Some(Diff { lines })
+}
+
+/// Diff two strings: character-level when both are a single line, otherwise a
+/// unified diff of their lines with the changed characters of paired lines
+/// highlighted.
+///
+/// Returns `None` when the strings are equal, or short enough that the label
+/// shows them in full.
+pub(crate) fn text_diff(expected: &str, actual: &str) -> Option<Diff> {
+    if expected == actual {
+        return None;
+    }
+
+    let multiline = expected.contains('\n') || actual.contains('\n');
+    if !multiline {
+        if expected.chars().count() < SHORT_TEXT && actual.chars().count() < SHORT_TEXT {
+            return None;
+        }
+        // Highlighting unrelated strings only adds noise
+        let (removed, added) = char_highlights(expected, actual)?;
+        let lines = vec![
+            DiffLine {
+                kind: LineKind::Removed,
+                text: expected.to_string(),
+                highlights: removed,
+            },
+            DiffLine {
+                kind: LineKind::Added,
+                text: actual.to_string(),
+                highlights: added,
+            },
+        ];
+        return Some(Diff { lines });
+    }
+
+    let expected: Vec<&str> = expected.split('\n').collect();
+    let actual: Vec<&str> = actual.split('\n').collect();
+    let ops = diff_sequences(&expected, &actual);
+
+    // Mark every op within CONTEXT_LINES of a change
+    let mut shown = vec![false; ops.len()];
+    for (i, op) in ops.iter().enumerate() {
+        if !matches!(op, Op::Same(_)) {
+            let start = i.saturating_sub(CONTEXT_LINES);
+            let end = (i + CONTEXT_LINES + 1).min(ops.len());
+            shown[start..end].iter_mut().for_each(|s| *s = true);
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut i = 0;
+    // 1-indexed line numbers of ops[i] on each side
+    let (mut old_line, mut new_line) = (1, 1);
+    while i < ops.len() {
+        if !shown[i] {
+            match ops[i] {
+                Op::Same(_) => {
+                    old_line += 1;
+                    new_line += 1;
+                }
+                Op::Removed(_) => old_line += 1,
+                Op::Added(_) => new_line += 1,
+            }
+            i += 1;
+            continue;
+        }
+
+        let end = (i..ops.len()).find(|&j| !shown[j]).unwrap_or(ops.len());
+        let hunk = &ops[i..end];
+        let old_len = hunk.iter().filter(|op| !matches!(op, Op::Added(_))).count();
+        let new_len = hunk
+            .iter()
+            .filter(|op| !matches!(op, Op::Removed(_)))
+            .count();
+        lines.push(DiffLine {
+            kind: LineKind::Hunk,
+            text: format!("@@ -{old_line},{old_len} +{new_line},{new_len} @@"),
+            highlights: Vec::new(),
+        });
+        push_hunk(&mut lines, hunk);
+
+        old_line += old_len;
+        new_line += new_len;
+        i = end;
+    }
+
+    Some(Diff { lines })
+}
+
+/// Add a hunk's lines. A run of removed lines directly followed by as many
+/// added lines is treated as a set of edited lines, and each pair gets its
+/// changed characters highlighted.
+fn push_hunk(lines: &mut Vec<DiffLine>, hunk: &[Op<'_>]) {
+    let mut i = 0;
+    while i < hunk.len() {
+        let removed = hunk[i..]
+            .iter()
+            .take_while(|op| matches!(op, Op::Removed(_)))
+            .count();
+        let added = hunk[i + removed..]
+            .iter()
+            .take_while(|op| matches!(op, Op::Added(_)))
+            .count();
+
+        if removed > 0 && removed == added {
+            let mut removed_lines = Vec::new();
+            let mut added_lines = Vec::new();
+            for k in 0..removed {
+                let old = op_text(hunk[i + k]);
+                let new = op_text(hunk[i + removed + k]);
+                let (old_highlights, new_highlights) =
+                    char_highlights(old, new).unwrap_or_default();
+                removed_lines.push(DiffLine {
+                    kind: LineKind::Removed,
+                    text: old.to_string(),
+                    highlights: old_highlights,
+                });
+                added_lines.push(DiffLine {
+                    kind: LineKind::Added,
+                    text: new.to_string(),
+                    highlights: new_highlights,
+                });
+            }
+            lines.extend(removed_lines);
+            lines.extend(added_lines);
+            i += removed + added;
+        } else {
+            lines.push(line_for(hunk[i]));
+            i += 1;
+        }
+    }
+}
+
+fn line_for(op: Op<'_>) -> DiffLine {
+    let (kind, text) = match op {
+        Op::Same(text) => (LineKind::Same, text),
+        Op::Removed(text) => (LineKind::Removed, text),
+        Op::Added(text) => (LineKind::Added, text),
+    };
+    DiffLine {
+        kind,
+        text: text.to_string(),
+        highlights: Vec::new(),
+    }
+}
+
+fn skipped_line(indent: usize) -> DiffLine {
+    DiffLine {
+        kind: LineKind::Skipped,
+        text: format!("{:indent$}...", ""),
+        highlights: Vec::new(),
+    }
+}
+
+fn op_text(op: Op<'_>) -> &str {
+    match op {
+        Op::Same(text) | Op::Removed(text) | Op::Added(text) => text,
+    }
+}
+
+fn indent_of(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Whether a `{:#?}` line starts a nested block, e.g. `server: Server {`.
+fn opens_block(text: &str) -> bool {
+    text.trim_end().ends_with(['{', '[', '('])
+}
+
+/// A line of `^` under the highlighted characters of `text`.
+fn carets(text: &str, highlights: &[Range<usize>]) -> String {
+    let mut out = String::new();
+    for (offset, _) in text.char_indices() {
+        if highlights.iter().any(|range| range.contains(&offset)) {
+            out.push('^');
+        } else {
+            out.push(' ');
+        }
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+/// Byte ranges of highlighted text within a line.
+type Highlights = Vec<Range<usize>>;
+
+/// Byte ranges of the characters that differ between two lines, for each side.
+///
+/// Returns `None` when fewer than half the characters of the longer line are
+/// shared, or the differences are scattered over more than
+/// [`MAX_HIGHLIGHTS`] places on a side, as the lines are then better read as a
+/// whole.
+fn char_highlights(old: &str, new: &str) -> Option<(Highlights, Highlights)> {
+    let old_chars: Vec<(usize, char)> = old.char_indices().collect();
+    let new_chars: Vec<(usize, char)> = new.char_indices().collect();
+    let old_keys: Vec<char> = old_chars.iter().map(|&(_, c)| c).collect();
+    let new_keys: Vec<char> = new_chars.iter().map(|&(_, c)| c).collect();
+
+    let mut old_ranges: Vec<Range<usize>> = Vec::new();
+    let mut new_ranges: Vec<Range<usize>> = Vec::new();
+    let ops = diff_sequences(&old_keys, &new_keys);
+    let shared = ops.iter().filter(|op| matches!(op, Op::Same(_))).count();
+    if shared * 2 < old_keys.len().max(new_keys.len()) {
+        return None;
+    }
+
+    let (mut i, mut j) = (0, 0);
+    for op in ops {
+        match op {
+            Op::Same(_) => {
+                i += 1;
+                j += 1;
+            }
+            Op::Removed(_) => {
+                let (offset, c) = old_chars[i];
+                extend_ranges(&mut old_ranges, offset..offset + c.len_utf8());
+                i += 1;
+            }
+            Op::Added(_) => {
+                let (offset, c) = new_chars[j];
+                extend_ranges(&mut new_ranges, offset..offset + c.len_utf8());
+                j += 1;
+            }
+        }
+    }
+    if old_ranges.len() > MAX_HIGHLIGHTS || new_ranges.len() > MAX_HIGHLIGHTS {
+        return None;
+    }
+    Some((
+        widen_to_words(old, old_ranges),
+        widen_to_words(new, new_ranges),
+    ))
+}
+
+/// Grow each range to cover the whole words it touches, so `name` → `email`
+/// reads as two changed words rather than a few scattered letters.
+fn widen_to_words(text: &str, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut widened: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        let start = text[..range.start]
+            .char_indices()
+            .rev()
+            .take_while(|&(_, c)| is_word(c))
+            .last()
+            .map_or(range.start, |(i, _)| i);
+        let end = text[range.end..]
+            .char_indices()
+            .find(|&(_, c)| !is_word(c))
+            .map_or(text.len(), |(i, _)| range.end + i);
+        match widened.last_mut() {
+            Some(last) if last.end >= start => last.end = last.end.max(end),
+            _ => widened.push(start..end),
+        }
+    }
+    widened
+}
+
+fn extend_ranges(ranges: &mut Vec<Range<usize>>, next: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == next.start => last.end = next.end,
+        _ => ranges.push(next),
+    }
+}
+
+/// Align two sequences by their longest common subsequence. Items are
+/// reported through their text form, so `T` is either a line or a `char`.
+fn diff_sequences<'a, T: Item<'a>>(old: &[T], new: &[T]) -> Vec<Op<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(o, n)| o == n).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(o, n)| o == n)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<Op<'a>> = old[..prefix]
+        .iter()
+        .map(|item| Op::Same(item.text()))
+        .collect();
+
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_TABLE_CELLS {
+        ops.extend(old_mid.iter().map(|item| Op::Removed(item.text())));
+        ops.extend(new_mid.iter().map(|item| Op::Added(item.text())));
+    } else {
+        // common[i][j] = length of the longest common subsequence of old_mid[i..] and new_mid[j..]
+        let width = new_mid.len() + 1;
+        let mut common = vec![0u32; (old_mid.len() + 1) * width];
+        for i in (0..old_mid.len()).rev() {
+            for j in (0..new_mid.len()).rev() {
+                common[i * width + j] = if old_mid[i] == new_mid[j] {
+                    common[(i + 1) * width + j + 1] + 1
+                } else {
+                    common[(i + 1) * width + j].max(common[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < old_mid.len() || j < new_mid.len() {
+            if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+                ops.push(Op::Same(old_mid[i].text()));
+                i += 1;
+                j += 1;
+            } else if j == new_mid.len()
+                || (i < old_mid.len() && common[(i + 1) * width + j] >= common[i * width + j + 1])
+            {
+                ops.push(Op::Removed(old_mid[i].text()));
+                i += 1;
+            } else {
+                ops.push(Op::Added(new_mid[j].text()));
+                j += 1;
+            }
+        }
+    }
+
+    ops.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|item| Op::Same(item.text())),
+    );
+    ops
+}
+
+/// Something [`diff_sequences`] can align.
+trait Item<'a>: PartialEq {
+    fn text(&self) -> &'a str;
+}
+
+impl<'a> Item<'a> for &'a str {
+    fn text(&self) -> &'a str {
+        self
+    }
+}
+
+impl Item<'static> for char {
+    /// Characters are only compared, never shown through their ops.
+    fn text(&self) -> &'static str {
+        ""
+    }
+}
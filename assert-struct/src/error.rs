@@ -2,13 +2,17 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::{Arc, LazyLock, RwLock};
 
-use annotate_snippets::{AnnotationKind, Level, Renderer, Snippet};
+use annotate_snippets::{AnnotationKind, Group, Level, Renderer, Snippet, Title};
+
+use crate::diff::Diff;
 
 thread_local! {
     static PLAIN_OUTPUT: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    static IGNORE_SOURCE_FILES: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
 }
 
 /// RAII guard that forces plain (non-colored) `ErrorReport` output on the current thread.
@@ -37,6 +41,33 @@ impl Drop for PlainOutputGuard {
     }
 }
 
+/// RAII guard that makes `ErrorReport` rendering on the current thread ignore
+/// source files, as if they were missing at runtime.
+///
+/// Intended for tests of the source text the macro embeds. Dropping the guard
+/// reads source files again.
+#[doc(hidden)]
+pub struct IgnoreSourceFilesGuard;
+
+impl Default for IgnoreSourceFilesGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IgnoreSourceFilesGuard {
+    pub fn new() -> Self {
+        IGNORE_SOURCE_FILES.with(|c| c.set(true));
+        IgnoreSourceFilesGuard
+    }
+}
+
+impl Drop for IgnoreSourceFilesGuard {
+    fn drop(&mut self) {
+        IGNORE_SOURCE_FILES.with(|c| c.set(false));
+    }
+}
+
 /// Global cache of source file contents, keyed by absolute path.
 /// Shared across all `assert_struct!` failures in a test binary so each
 /// file is read at most once regardless of how many assertions fail.
@@ -44,6 +75,9 @@ static SOURCE_CACHE: LazyLock<RwLock<HashMap<PathBuf, Arc<str>>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
 
 fn cached_source(path: &PathBuf) -> Option<Arc<str>> {
+    if IGNORE_SOURCE_FILES.with(|c| c.get()) {
+        return None;
+    }
     // Fast path: already cached.
     if let Ok(cache) = SOURCE_CACHE.read() {
         if let Some(content) = cache.get(path) {
@@ -58,6 +92,34 @@ fn cached_source(path: &PathBuf) -> Option<Arc<str>> {
     Some(content)
 }
 
+/// The text a snippet is rendered from: a source file, or the text of the
+/// invocation that the macro embedded.
+enum SourceText {
+    File(Arc<str>),
+    Embedded(&'static str),
+}
+
+impl std::ops::Deref for SourceText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            SourceText::File(text) => text,
+            SourceText::Embedded(text) => text,
+        }
+    }
+}
+
+/// The sources a report's snippets borrow, read before rendering.
+struct Sources {
+    /// The report's source and the line it starts at: 1 for the file, the
+    /// invocation's first line for embedded text
+    text: Option<(SourceText, u32)>,
+    /// The file of the call site of the `#[track_caller]` function the
+    /// assertion is in
+    caller: Option<Arc<str>>,
+}
+
 /// Derive the absolute source file path from two compile-time constants:
 /// - `manifest_dir`: value of `env!("CARGO_MANIFEST_DIR")` — absolute path to the package root
 /// - `file_path`: value of `file!()` — path relative to the workspace root
@@ -117,8 +179,22 @@ fn byte_offset_of(source: &str, line: u32, col: u32) -> usize {
     (line_start + col as usize).min(source.len())
 }
 
+/// Label of the call that led to an assertion in a `#[track_caller]` function.
+const CALLER_LABEL: &str = "called from here";
+
+/// The span of a call at `caller`: from the start of the call to the end of
+/// its line.
+fn caller_range(source: &str, caller: &std::panic::Location<'_>) -> Range<usize> {
+    let start = byte_offset_of(source, caller.line(), caller.column().saturating_sub(1));
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |i| start + i);
+    let end = start + source[start..line_end].trim_end().len();
+    start..end.max(start + 1)
+}
+
 /// Context information for a failed assertion.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[allow(dead_code)]
 struct ErrorContext {
     actual_value: String,
@@ -126,22 +202,53 @@ struct ErrorContext {
     expected_value: Option<String>,
     /// The specific pattern node that failed
     error_node: &'static PatternNode,
+    /// Extra detail computed at runtime, e.g. the difference for `~` patterns
+    note: Option<String>,
+    /// Label computed at runtime, replacing the one derived from the node kind
+    label: Option<String>,
+    /// Diff of the expected and actual values, shown below the snippet
+    diff: Option<Diff>,
+    /// Multi-line explanation shown below the snippet, e.g. a map's key summary
+    summary: Option<String>,
+    /// Element indices of the enclosing slices and quantifiers, outermost first
+    indices: Vec<usize>,
+    /// Reports of the assertions that failed inside this node's closure or
+    /// method call
+    nested: Vec<ErrorReport>,
 }
 
 /// Collected assertion errors for reporting.
-#[derive(Debug)]
+///
+/// Returned by [`check_struct!`](crate::check_struct) when an assertion fails.
+/// The `Display` impl renders the annotated source snippet that
+/// [`assert_struct!`](crate::assert_struct) panics with, and
+/// [`failures`](ErrorReport::failures) exposes each mismatch individually.
 pub struct ErrorReport {
     errors: Vec<ErrorContext>,
-    /// Absolute path used to read the file from disk.
-    abs_path: PathBuf,
+    /// The package root (`CARGO_MANIFEST_DIR`), to find the files on disk.
+    manifest_dir: &'static str,
     /// Workspace-relative path used for display (the raw `file!()` value).
-    rel_path: String,
+    rel_path: &'static str,
+    /// Where the `#[track_caller]` function containing the assertion was called.
+    caller: Option<&'static std::panic::Location<'static>>,
+    /// The macro invocation's text, used when the file can't be read.
+    embedded_source: Option<&'static EmbeddedSource>,
+    /// Custom message passed as trailing `format!` arguments to the macro.
+    /// Boxed to keep `Result<_, ErrorReport>` small.
+    message: Option<Box<str>>,
+    /// Element indices of the slices and quantifiers currently being checked.
+    indices: Vec<usize>,
+    /// Whether failures keep their formatted values. Probe reports only count them.
+    record_values: bool,
 }
 
 /// Tree-based pattern representation, generated by the macro at compile time.
+#[doc(hidden)]
 pub struct PatternNode {
     pub kind: NodeKind,
     pub parent: Option<&'static PatternNode>,
+    /// How this node is reached from its parent, for the failure's field path.
+    pub step: PathStep,
     /// 1-indexed line of the first character of this pattern in source.
     pub line_start: u32,
     /// 0-indexed column of the first character of this pattern in source.
@@ -152,7 +259,18 @@ pub struct PatternNode {
     pub col_end: u32,
 }
 
+/// Source text of a macro invocation, generated by the macro at compile time.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct EmbeddedSource {
+    /// 1-indexed line of the first character of `text` in source.
+    pub first_line: u32,
+    /// The invocation's tokens at their original lines and columns.
+    pub text: &'static str,
+}
+
 /// The kind of pattern node.
+#[doc(hidden)]
 #[derive(Debug)]
 pub enum NodeKind {
     // Collection patterns
@@ -194,6 +312,11 @@ pub enum NodeKind {
     Range {
         pattern: &'static str,
     },
+    Approx {
+        expected: &'static str,
+        tolerance: &'static str,
+        relative: bool,
+    },
     Regex {
         pattern: &'static str,
     },
@@ -201,6 +324,22 @@ pub enum NodeKind {
         expr: &'static str,
     },
 
+    Quantifier {
+        kind: QuantifierKind,
+        inner: &'static PatternNode,
+    },
+
+    // Combinators
+    Or {
+        alternatives: &'static [&'static PatternNode],
+    },
+    Not {
+        inner: &'static PatternNode,
+    },
+    And {
+        conjuncts: &'static [&'static PatternNode],
+    },
+
     // Special
     Wildcard,
     Closure {
@@ -208,7 +347,76 @@ pub enum NodeKind {
     },
 }
 
+impl NodeKind {
+    /// The public kind of this node.
+    pub(crate) fn pattern_kind(&self) -> PatternKind {
+        match self {
+            NodeKind::Slice { .. } => PatternKind::Slice,
+            NodeKind::Set { .. } => PatternKind::Set,
+            NodeKind::Tuple { .. } => PatternKind::Tuple,
+            NodeKind::Map { .. } => PatternKind::Map,
+            NodeKind::Struct { .. } => PatternKind::Struct,
+            NodeKind::EnumVariant { .. } => PatternKind::EnumVariant,
+            NodeKind::Simple { .. } => PatternKind::Simple,
+            NodeKind::Comparison { .. } => PatternKind::Comparison,
+            NodeKind::Range { .. } => PatternKind::Range,
+            NodeKind::Approx { .. } => PatternKind::Approx,
+            NodeKind::Regex { .. } => PatternKind::Regex,
+            NodeKind::Like { .. } => PatternKind::Like,
+            NodeKind::Quantifier { .. } => PatternKind::Quantifier,
+            NodeKind::Or { .. } => PatternKind::Or,
+            NodeKind::Not { .. } => PatternKind::Not,
+            NodeKind::And { .. } => PatternKind::And,
+            NodeKind::Wildcard => PatternKind::Wildcard,
+            NodeKind::Closure { .. } => PatternKind::Closure,
+        }
+    }
+}
+
+/// One step of the field path to a pattern node, e.g. `.customer` in
+/// `order.customer.address`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStep {
+    /// The value given to the macro, e.g. `order`
+    Root(&'static str),
+    /// Adds nothing to the path, e.g. an alternative of `|`
+    Same,
+    /// A struct field with any field operations, e.g. `.name.len()`
+    Field(&'static str),
+    /// A tuple element, or an argument of a variant that has several, e.g. `.1`
+    Position(usize),
+    /// A map entry, e.g. `["key"]`
+    Key(&'static str),
+    /// A slice or quantifier element, whose index is only known at runtime
+    Element,
+}
+
+/// How a quantifier pattern applies its inner pattern to the elements.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantifierKind {
+    /// `[all: pattern]`
+    All,
+    /// `[any: pattern]`
+    Any,
+    /// `[none: pattern]`
+    None,
+}
+
+impl QuantifierKind {
+    /// Get the keyword for this quantifier
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuantifierKind::All => "all",
+            QuantifierKind::Any => "any",
+            QuantifierKind::None => "none",
+        }
+    }
+}
+
 /// Comparison operators used in patterns.
+#[doc(hidden)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ComparisonOp {
     Less,
@@ -234,29 +442,163 @@ impl ComparisonOp {
 }
 
 impl ErrorReport {
-    pub fn new(manifest_dir: &str, file_path: &str) -> Self {
+    #[doc(hidden)]
+    pub fn new(manifest_dir: &'static str, file_path: &'static str) -> Self {
         ErrorReport {
             errors: Vec::new(),
-            abs_path: absolute_source_path(manifest_dir, file_path),
-            rel_path: file_path.to_string(),
+            manifest_dir,
+            rel_path: file_path,
+            caller: None,
+            embedded_source: None,
+            message: None,
+            indices: Vec::new(),
+            record_values: true,
         }
     }
 
-    /// Create a disposable probe report used during set-pattern backtracking.
+    /// Create a disposable probe report used while probing whether a pattern matches.
     /// The report is never displayed; callers check `is_empty()` to determine
-    /// whether a trial pattern assertion succeeded.
+    /// whether a trial pattern assertion succeeded. Failures are counted but their
+    /// values are never formatted.
+    #[doc(hidden)]
     pub fn new_probe() -> Self {
         ErrorReport {
             errors: Vec::new(),
-            abs_path: PathBuf::new(),
-            rel_path: String::new(),
+            manifest_dir: "",
+            rel_path: "",
+            caller: None,
+            embedded_source: None,
+            message: None,
+            indices: Vec::new(),
+            record_values: false,
         }
     }
 
+    /// Like [`new_probe`](ErrorReport::new_probe), but keeps the formatted values
+    /// so the failures can be described.
+    pub(crate) fn new_recording_probe() -> Self {
+        ErrorReport {
+            record_values: true,
+            ..ErrorReport::new_probe()
+        }
+    }
+
+    /// Whether failures pushed to this report keep their values. Generated code
+    /// checks this before formatting anything.
+    #[doc(hidden)]
+    pub fn records_values(&self) -> bool {
+        self.record_values
+    }
+
+    /// Whether no failures were collected.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
 
+    /// Attach the macro invocation's source text, to render the snippet from
+    /// when the file can't be read.
+    #[doc(hidden)]
+    pub fn with_source(mut self, source: &'static EmbeddedSource) -> Self {
+        self.embedded_source = Some(source);
+        self
+    }
+
+    /// Record where the `#[track_caller]` function containing the assertion
+    /// was called. `line` is the line of the macro invocation: outside such a
+    /// function, the caller is the assertion itself and isn't recorded.
+    #[doc(hidden)]
+    pub fn with_caller(
+        mut self,
+        caller: &'static std::panic::Location<'static>,
+        line: u32,
+    ) -> Self {
+        if caller.file() != self.rel_path || caller.line() != line {
+            self.caller = Some(caller);
+        }
+        self
+    }
+
+    /// Where the `#[track_caller]` function containing the assertion was
+    /// called, if the assertion is in one.
+    pub fn caller(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.caller
+    }
+
+    /// Attach the custom failure message given to the macro.
+    #[doc(hidden)]
+    pub fn with_message(mut self, message: String) -> Self {
+        self.message = Some(message.into_boxed_str());
+        self
+    }
+
+    /// The custom failure message, if one was given to the macro.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Number of failures collected in this report.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Iterate over the individual failures, in the order they were detected.
+    pub fn failures(&self) -> Failures<'_> {
+        Failures {
+            errors: self.errors.iter(),
+            report: self,
+        }
+    }
+
+    /// Render the failures as JSON Lines: one object per failure, each on its
+    /// own line, for tools that collect test failures.
+    ///
+    /// This is also what `Display` renders when the `ASSERT_STRUCT_FORMAT`
+    /// environment variable is set to `json`. Each object has the fields
+    /// `file`, `line`, `column`, `end_line`, `end_column` (1-indexed, the end
+    /// inclusive), `path`, `kind` (see [`PatternKind::as_str`]), `pattern`,
+    /// `actual`, `expected` and `message`; the last two may be `null`. The
+    /// failures of nested assertions follow the failure they belong to.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        for error in &self.errors {
+            let node = error.error_node;
+            let fields = [
+                ("file", json_string(self.rel_path)),
+                ("line", node.line_start.to_string()),
+                ("column", (node.col_start + 1).to_string()),
+                ("end_line", node.line_end.to_string()),
+                ("end_column", node.col_end.to_string()),
+                ("path", json_string(&element_path(error))),
+                ("kind", json_string(node.kind.pattern_kind().as_str())),
+                ("pattern", json_string(&node.to_string())),
+                ("actual", json_string(&error.actual_value)),
+                (
+                    "expected",
+                    error
+                        .expected_value
+                        .as_deref()
+                        .map_or("null".into(), json_string),
+                ),
+                (
+                    "message",
+                    self.message.as_deref().map_or("null".into(), json_string),
+                ),
+            ];
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(name, value)| format!("\"{}\":{}", name, value))
+                .collect();
+            out.push('{');
+            out.push_str(&fields.join(","));
+            out.push_str("}\n");
+            for nested in &error.nested {
+                out.push_str(&nested.to_json());
+            }
+        }
+        out
+    }
+
+    #[doc(hidden)]
     pub fn push(
         &mut self,
         error_node: &'static PatternNode,
@@ -267,12 +609,481 @@ impl ErrorReport {
             actual_value: actual,
             expected_value: expected,
             error_node,
+            note: None,
+            label: None,
+            diff: None,
+            summary: None,
+            indices: self.indices.clone(),
+            nested: Vec::new(),
+        });
+    }
+
+    /// Record a failure without formatting its values, for reports that don't
+    /// [record them](ErrorReport::records_values).
+    #[doc(hidden)]
+    pub fn push_unformatted(&mut self, error_node: &'static PatternNode) {
+        self.errors.push(ErrorContext {
+            actual_value: String::new(),
+            expected_value: None,
+            error_node,
+            note: None,
+            label: None,
+            diff: None,
+            summary: None,
+            indices: Vec::new(),
+            nested: Vec::new(),
+        });
+    }
+
+    /// Attach a diff between the `{:#?}` renderings of the expected and actual
+    /// values to the last failure. Nothing is attached if the expected value
+    /// could not be formatted, or if both renderings fit on one line.
+    #[doc(hidden)]
+    pub fn attach_diff(&mut self, expected: Option<String>, actual: String) {
+        if let (Some(expected), Some(error)) = (expected, self.errors.last_mut()) {
+            error.diff = crate::diff::structural_diff(&expected, &actual);
+        }
+    }
+
+    /// Attach a diff between the expected and actual strings to the last
+    /// failure: character-level for single lines, a unified line diff for
+    /// multiline text. Nothing is attached for short single-line strings.
+    #[doc(hidden)]
+    pub fn attach_text_diff(&mut self, expected: &str, actual: &str) {
+        if let Some(error) = self.errors.last_mut() {
+            error.diff = crate::diff::text_diff(expected, actual);
+        }
+    }
+
+    /// Attach an explanation to the last failure, shown below the snippet.
+    pub(crate) fn attach_summary(&mut self, summary: String) {
+        if let Some(error) = self.errors.last_mut() {
+            error.summary = Some(summary);
+        }
+    }
+
+    /// Like [`push`](ErrorReport::push), with extra detail for the label.
+    pub(crate) fn push_with_note(
+        &mut self,
+        error_node: &'static PatternNode,
+        actual: String,
+        expected: Option<String>,
+        note: String,
+    ) {
+        self.errors.push(ErrorContext {
+            actual_value: actual,
+            expected_value: expected,
+            error_node,
+            note: Some(note),
+            label: None,
+            diff: None,
+            summary: None,
+            indices: self.indices.clone(),
+            nested: Vec::new(),
+        });
+    }
+
+    /// Like [`push`](ErrorReport::push), with a label composed by the caller.
+    pub(crate) fn push_with_label(
+        &mut self,
+        error_node: &'static PatternNode,
+        actual: String,
+        label: String,
+    ) {
+        self.errors.push(ErrorContext {
+            actual_value: actual,
+            expected_value: None,
+            error_node,
+            note: None,
+            label: Some(label),
+            diff: None,
+            summary: None,
+            indices: self.indices.clone(),
+            nested: Vec::new(),
         });
     }
+
+    /// Record that assertions inside the closure or method call of
+    /// `error_node` failed, with their reports.
+    pub(crate) fn push_nested(
+        &mut self,
+        error_node: &'static PatternNode,
+        nested: Vec<ErrorReport>,
+    ) {
+        if !self.record_values {
+            self.push_unformatted(error_node);
+            return;
+        }
+        self.errors.push(ErrorContext {
+            actual_value: String::new(),
+            expected_value: None,
+            error_node,
+            note: None,
+            label: Some("nested assertion failed".to_string()),
+            diff: None,
+            summary: None,
+            indices: self.indices.clone(),
+            nested,
+        });
+    }
+
+    /// Describe this report's failures for the element at `index`, with paths
+    /// relative to `root`, e.g. `[2].kind: expected "click", got "scroll"`.
+    pub(crate) fn describe_element(&self, root: &PatternNode, index: usize) -> String {
+        self.errors
+            .iter()
+            .map(|error| {
+                format!(
+                    "[{}]{}: {}",
+                    index,
+                    path_between(error, Some(root)),
+                    node_label(error)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+
+    /// Enter the element at `index` of the innermost slice or quantifier. Errors pushed
+    /// until the matching [`pop_index`](ErrorReport::pop_index) are labelled
+    /// with the element's path, e.g. `items[3].price`.
+    #[doc(hidden)]
+    pub fn push_index(&mut self, index: usize) {
+        self.indices.push(index);
+    }
+
+    /// Leave the element entered by the last [`push_index`](ErrorReport::push_index).
+    #[doc(hidden)]
+    pub fn pop_index(&mut self) {
+        self.indices.pop();
+    }
+}
+
+impl<'a> IntoIterator for &'a ErrorReport {
+    type Item = Failure<'a>;
+    type IntoIter = Failures<'a>;
+
+    fn into_iter(self) -> Failures<'a> {
+        self.failures()
+    }
+}
+
+/// Iterator over the failures of an [`ErrorReport`], returned by
+/// [`ErrorReport::failures`].
+#[derive(Clone)]
+pub struct Failures<'a> {
+    errors: std::slice::Iter<'a, ErrorContext>,
+    report: &'a ErrorReport,
+}
+
+impl<'a> Failures<'a> {
+    fn wrap(&self, context: &'a ErrorContext) -> Failure<'a> {
+        Failure {
+            context,
+            report: self.report,
+        }
+    }
+}
+
+impl<'a> Iterator for Failures<'a> {
+    type Item = Failure<'a>;
+
+    fn next(&mut self) -> Option<Failure<'a>> {
+        let context = self.errors.next()?;
+        Some(self.wrap(context))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.errors.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Failures<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let context = self.errors.next_back()?;
+        Some(self.wrap(context))
+    }
+}
+
+impl ExactSizeIterator for Failures<'_> {}
+
+impl std::iter::FusedIterator for Failures<'_> {}
+
+impl fmt::Debug for Failures<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A single pattern mismatch recorded in an [`ErrorReport`].
+///
+/// `Display` renders the failure's label, e.g. `user.age: expected 31, got 30`.
+#[derive(Clone, Copy)]
+pub struct Failure<'a> {
+    context: &'a ErrorContext,
+    report: &'a ErrorReport,
+}
+
+impl<'a> Failure<'a> {
+    /// The `Debug` rendering of the value that failed to match.
+    pub fn actual(&self) -> &'a str {
+        &self.context.actual_value
+    }
+
+    /// The expected value, for patterns that carry one (e.g. `== expected`).
+    pub fn expected(&self) -> Option<&'a str> {
+        self.context.expected_value.as_deref()
+    }
+
+    /// The path from the asserted value to the value that failed, e.g.
+    /// `order.items[3].price`.
+    pub fn path(&self) -> String {
+        element_path(self.context)
+    }
+
+    /// The kind of the pattern that failed.
+    pub fn kind(&self) -> PatternKind {
+        self.context.error_node.kind.pattern_kind()
+    }
+
+    /// The source text of the pattern that failed, e.g. `> 18`.
+    pub fn pattern(&self) -> String {
+        self.context.error_node.to_string()
+    }
+
+    /// The reports of the assertions that failed inside the pattern's closure
+    /// or field method call, for a failure labelled `nested assertion failed`.
+    pub fn nested(&self) -> &'a [ErrorReport] {
+        &self.context.nested
+    }
+
+    /// Where the failing pattern is written.
+    pub fn location(&self) -> Location {
+        let node = self.context.error_node;
+        Location {
+            file: self.report.rel_path,
+            line: node.line_start,
+            column: node.col_start + 1,
+            end_line: node.line_end,
+            end_column: node.col_end,
+        }
+    }
+}
+
+impl fmt::Debug for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Failure")
+            .field("path", &self.path())
+            .field("kind", &self.kind())
+            .field("pattern", &self.pattern())
+            .field("location", &self.location())
+            .field("actual", &self.actual())
+            .field("expected", &self.expected())
+            .finish()
+    }
+}
+
+/// The source span of a failing pattern. Lines and columns are 1-indexed, and
+/// the end is inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    file: &'static str,
+    line: u32,
+    column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+impl Location {
+    /// Workspace-relative path of the file, as given by `file!()`.
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Line of the first character of the pattern.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Column of the first character of the pattern.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// Line of the last character of the pattern.
+    pub fn end_line(&self) -> u32 {
+        self.end_line
+    }
+
+    /// Column of the last character of the pattern.
+    pub fn end_column(&self) -> u32 {
+        self.end_column
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// The kind of pattern that failed, e.g. [`Comparison`](PatternKind::Comparison)
+/// for `> 18`.
+///
+/// New kinds may be added as the pattern syntax grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatternKind {
+    /// `Type { field: pattern, .. }` or `{ field: pattern, .. }`
+    Struct,
+    /// `(pattern, pattern)`
+    Tuple,
+    /// `[pattern, pattern, ..]`
+    Slice,
+    /// `#(pattern, pattern, ..)`
+    Set,
+    /// `#{ "key": pattern, .. }`
+    Map,
+    /// `Some(pattern)`, `Status::Active`
+    EnumVariant,
+    /// A literal value or expression
+    Simple,
+    /// `< value`, `!= value` and the other comparison operators
+    Comparison,
+    /// `1..=10`
+    Range,
+    /// `~ 1.0 +- 0.01`
+    Approx,
+    /// `=~ r"regex"`
+    Regex,
+    /// `=~ expression`, using [`Like`](crate::Like)
+    Like,
+    /// `[all: pattern]`, `[any: pattern]`, `[none: pattern]`
+    Quantifier,
+    /// `pattern | pattern`
+    Or,
+    /// `!pattern`
+    Not,
+    /// `pattern && pattern`
+    And,
+    /// `_`
+    Wildcard,
+    /// `|value| condition`
+    Closure,
+}
+
+impl PatternKind {
+    /// The name of this kind, e.g. `"Comparison"`, as used in JSON output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PatternKind::Struct => "Struct",
+            PatternKind::Tuple => "Tuple",
+            PatternKind::Slice => "Slice",
+            PatternKind::Set => "Set",
+            PatternKind::Map => "Map",
+            PatternKind::EnumVariant => "EnumVariant",
+            PatternKind::Simple => "Simple",
+            PatternKind::Comparison => "Comparison",
+            PatternKind::Range => "Range",
+            PatternKind::Approx => "Approx",
+            PatternKind::Regex => "Regex",
+            PatternKind::Like => "Like",
+            PatternKind::Quantifier => "Quantifier",
+            PatternKind::Or => "Or",
+            PatternKind::Not => "Not",
+            PatternKind::And => "And",
+            PatternKind::Wildcard => "Wildcard",
+            PatternKind::Closure => "Closure",
+        }
+    }
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&error_label(self.context))
+    }
 }
 
 /// Build a human-readable annotation label for a failed assertion.
+///
+/// Labels are prefixed with the path to the offending value, e.g.
+/// `order.items[3].price: got 0`, so failures can be found in large patterns
+/// and in logs without the snippet.
 fn error_label(error: &ErrorContext) -> String {
+    let label = node_label(error);
+    let path = element_path(error);
+    if path.is_empty() {
+        label
+    } else {
+        format!("{}: {}", path, label)
+    }
+}
+
+/// Build the path from the value given to the macro to the failing node,
+/// filling in slice and quantifier element indices, e.g. `order.items[3].price`.
+fn element_path(error: &ErrorContext) -> String {
+    let path = path_between(error, None);
+    path.strip_prefix('.').unwrap_or(&path).to_string()
+}
+
+/// Build the path to the failing node from `root`, or from the value given to
+/// the macro when `root` is `None`. Field segments keep their leading `.`.
+fn path_between(error: &ErrorContext, root: Option<&PatternNode>) -> String {
+    let mut indices = error.indices.iter().rev();
+    let mut segments = Vec::new();
+
+    // A `none` quantifier reports the matching element itself
+    if let NodeKind::Quantifier {
+        kind: QuantifierKind::None,
+        ..
+    } = error.error_node.kind
+    {
+        if let Some(index) = indices.next() {
+            segments.push(format!("[{}]", index));
+        }
+    }
+
+    let mut node = Some(error.error_node);
+    while let Some(current) = node {
+        if root.is_some_and(|root| std::ptr::eq(current, root)) {
+            break;
+        }
+        match current.step {
+            PathStep::Root(expr) => segments.push(expr.to_string()),
+            PathStep::Same => {}
+            PathStep::Field(field) => segments.push(field.to_string()),
+            PathStep::Position(i) => segments.push(format!(".{}", i)),
+            PathStep::Key(key) => segments.push(format!("[{}]", key)),
+            PathStep::Element => {
+                if let Some(index) = indices.next() {
+                    segments.push(format!("[{}]", index));
+                }
+            }
+        }
+        node = current.parent;
+    }
+
+    segments.iter().rev().map(String::as_str).collect()
+}
+
+/// Build the label describing why a node failed, without any path prefix.
+fn node_label(error: &ErrorContext) -> String {
+    if let Some(label) = &error.label {
+        return label.clone();
+    }
+    // The values themselves are shown in the diff below the snippet
+    if error.diff.is_some() {
+        return match (&error.expected_value, &error.error_node.kind) {
+            (Some(expected), _) => {
+                format!("expected {}, got a different value (see diff)", expected)
+            }
+            (None, NodeKind::EnumVariant { .. }) => format!(
+                "expected {}, got a different value (see diff)",
+                error.error_node
+            ),
+            (None, _) => "got a different value (see diff)".to_string(),
+        };
+    }
     match &error.error_node.kind {
         NodeKind::Comparison {
             op: ComparisonOp::Equal,
@@ -286,18 +1097,6 @@ fn error_label(error: &ErrorContext) -> String {
             "expected variant {}, got {}",
             error.error_node, error.actual_value,
         ),
-        NodeKind::Slice { items, rest } => {
-            if *rest {
-                format!("slice pattern mismatch, got {}", error.actual_value)
-            } else {
-                let n = items.len();
-                let suffix = if n == 1 { "element" } else { "elements" };
-                format!(
-                    "expected slice with {} {}, got {}",
-                    n, suffix, error.actual_value
-                )
-            }
-        }
         NodeKind::Set { rest, .. } => {
             if *rest {
                 format!("set pattern mismatch, got {}", error.actual_value)
@@ -309,81 +1108,293 @@ fn error_label(error: &ErrorContext) -> String {
             "closure condition not satisfied, got {}",
             error.actual_value,
         ),
+        NodeKind::Approx { .. } => format!(
+            "expected {}, got {} ({})",
+            error.expected_value.as_deref().unwrap_or("?"),
+            error.actual_value,
+            error.note.as_deref().unwrap_or("not a number"),
+        ),
+        NodeKind::Quantifier {
+            kind: QuantifierKind::Any,
+            ..
+        } => format!("no element matched, got {}", error.actual_value),
+        NodeKind::Quantifier {
+            kind: QuantifierKind::None,
+            inner,
+        } => format!(
+            "expected no element to match {}, got {}",
+            inner, error.actual_value,
+        ),
+        NodeKind::Or { .. } => format!(
+            "none of the alternatives matched, got {}",
+            error.actual_value,
+        ),
+        NodeKind::Not { inner } => format!(
+            "expected not to match {}, got {}",
+            inner, error.actual_value,
+        ),
         _ => format!("got {}", error.actual_value),
     }
 }
 
+// Renders with the renderer selected by `ASSERT_STRUCT_FORMAT`, the
+// annotated source snippet by default.
 impl fmt::Display for ErrorReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.errors.is_empty() {
             return Ok(());
         }
+        crate::render::from_env().render(self, f)
+    }
+}
 
-        let source_content = cached_source(&self.abs_path);
-
-        // Pre-compute labels so their lifetimes outlive the report construction.
-        let labels: Vec<String> = self.errors.iter().map(error_label).collect();
+impl ErrorReport {
+    /// Render the report with `renderer` instead of the one selected by the
+    /// environment.
+    pub fn render(&self, renderer: &dyn crate::render::Render) -> String {
+        let mut out = String::new();
+        if !self.errors.is_empty() {
+            // Writing to a `String` can't fail
+            let _ = renderer.render(self, &mut out);
+        }
+        out
+    }
 
-        let renderer = if PLAIN_OUTPUT.with(|c| c.get())
+    /// Render the annotated source snippet, or a plain listing when the
+    /// source file can't be read. Nested assertions follow as notes.
+    pub(crate) fn render_snippets(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let styled = !(PLAIN_OUTPUT.with(|c| c.get())
             || std::env::var_os("NO_COLOR").is_some()
-            || !std::io::IsTerminal::is_terminal(&std::io::stderr())
-        {
-            Renderer::plain()
-        } else {
+            || !std::io::IsTerminal::is_terminal(&std::io::stderr()));
+        let renderer = if styled {
             Renderer::styled()
+        } else {
+            Renderer::plain()
         };
 
-        if let Some(source) = &source_content {
-            let annotations: Vec<_> = self
-                .errors
-                .iter()
-                .zip(labels.iter())
-                .map(|(error, label)| {
-                    let start = byte_offset_of(
-                        source,
-                        error.error_node.line_start,
-                        error.error_node.col_start,
-                    );
-                    let end =
-                        byte_offset_of(source, error.error_node.line_end, error.error_node.col_end)
-                            .max(start + 1);
-                    AnnotationKind::Primary
-                        .span(start..end)
-                        .label(label.as_str())
-                })
-                .collect();
+        // Read every source up front, so the snippets can borrow them
+        let mut reports = Vec::new();
+        self.collect_reports(&mut reports);
+        let sources: Vec<_> = reports.iter().map(|report| report.sources()).collect();
+        if sources[0].text.is_none() {
+            return self.render_listing(f, styled);
+        }
+
+        let groups: Vec<_> = reports
+            .iter()
+            .zip(&sources)
+            .enumerate()
+            .map(|(i, (report, sources))| {
+                let title = if i == 0 {
+                    Level::ERROR.primary_title(report.title())
+                } else {
+                    Level::NOTE.secondary_title(format!("nested {}", report.title()))
+                };
+                match &sources.text {
+                    Some(text) => report.snippet_group(text, sources, title, styled),
+                    None => {
+                        let mut listing = String::new();
+                        // Writing to a `String` can't fail
+                        let _ = report.render_listing(&mut listing, styled);
+                        Group::with_title(title).element(Level::NOTE.message(listing))
+                    }
+                }
+            })
+            .collect();
 
-            let snippet = Snippet::source(&**source)
-                .line_start(1)
-                .path(&self.rel_path)
-                .annotations(annotations);
+        write!(f, "{}", renderer.render(&groups))
+    }
 
-            let report = Level::ERROR
-                .primary_title("assert_struct! failed")
-                .element(snippet);
+    /// This report followed by the reports of its nested assertions, depth
+    /// first.
+    fn collect_reports<'a>(&'a self, reports: &mut Vec<&'a ErrorReport>) {
+        reports.push(self);
+        for nested in self.errors.iter().flat_map(|error| &error.nested) {
+            nested.collect_reports(reports);
+        }
+    }
 
-            write!(f, "{}", renderer.render(&[report]))?;
-        } else {
-            // Fallback when the source file cannot be read: show location + description.
-            write!(f, "assert_struct! failed:")?;
-            for (error, label) in self.errors.iter().zip(labels.iter()) {
-                write!(
-                    f,
-                    "\n  --> {}:{}\n  {label}",
-                    self.rel_path, error.error_node.line_start
-                )?;
+    fn title(&self) -> String {
+        match &self.message {
+            Some(message) => format!("assert_struct! failed: {message}"),
+            None => "assert_struct! failed".to_string(),
+        }
+    }
+
+    fn sources(&self) -> Sources {
+        let text = match cached_source(&absolute_source_path(self.manifest_dir, self.rel_path)) {
+            Some(source) => Some((SourceText::File(source), 1)),
+            None => self
+                .embedded_source
+                .map(|embedded| (SourceText::Embedded(embedded.text), embedded.first_line)),
+        };
+        let caller = self.caller.and_then(|caller| {
+            cached_source(&absolute_source_path(self.manifest_dir, caller.file()))
+        });
+        Sources { text, caller }
+    }
+
+    /// The snippet annotated with this report's failures, under `title`.
+    fn snippet_group<'a>(
+        &'a self,
+        (source, first_line): &'a (SourceText, u32),
+        sources: &'a Sources,
+        title: Title<'a>,
+        styled: bool,
+    ) -> Group<'a> {
+        let source: &'a str = source;
+        let offset = |line: u32, col: u32| {
+            // Line 0 marks a synthetic span and stays 0
+            let line = line.saturating_sub(first_line - 1);
+            byte_offset_of(source, line, col)
+        };
+        let node_range = |node: &PatternNode| {
+            let start = offset(node.line_start, node.col_start);
+            let end = offset(node.line_end, node.col_end).max(start + 1);
+            start..end
+        };
+
+        let mut annotations = Vec::new();
+        for error in &self.errors {
+            annotations.push(
+                AnnotationKind::Primary
+                    .span(node_range(error.error_node))
+                    .label(error_label(error)),
+            );
+
+            // Point at each alternative that was tried
+            if let NodeKind::Or { alternatives } = &error.error_node.kind {
+                annotations.extend(
+                    alternatives
+                        .iter()
+                        .filter(|alt| alt.line_start != 0)
+                        .map(|alt| AnnotationKind::Context.span(node_range(alt))),
+                );
             }
         }
 
+        // The call of the `#[track_caller]` function the assertion is in:
+        // annotated in the same snippet when it is in the same file, in a
+        // snippet of its own, or named in a note when its file can't be read
+        let mut caller_snippet = None;
+        let mut caller_note = None;
+        if let Some(caller) = self.caller {
+            match sources.caller.as_deref() {
+                Some(caller_source) if caller.file() == self.rel_path && *first_line == 1 => {
+                    annotations.push(
+                        AnnotationKind::Context
+                            .span(caller_range(caller_source, caller))
+                            .label(CALLER_LABEL),
+                    );
+                }
+                Some(caller_source) => {
+                    caller_snippet = Some(
+                        Snippet::source(caller_source)
+                            .line_start(1)
+                            .path(caller.file())
+                            .annotation(
+                                AnnotationKind::Context
+                                    .span(caller_range(caller_source, caller))
+                                    .label(CALLER_LABEL),
+                            ),
+                    );
+                }
+                None => caller_note = Some(Level::NOTE.message(format!("called from {caller}"))),
+            }
+        }
+
+        let snippet = Snippet::source(source)
+            .line_start(*first_line as usize)
+            .path(self.rel_path)
+            .annotations(annotations);
+
+        let notes = self.errors.iter().flat_map(|error| {
+            let summary = error
+                .summary
+                .as_deref()
+                .map(|summary| Level::NOTE.message(summary));
+            let diff = error.diff.as_ref().map(|diff| {
+                Level::NOTE.message(format!(
+                    "diff (- expected, + actual):\n{}",
+                    diff.render(styled)
+                ))
+            });
+            summary.into_iter().chain(diff)
+        });
+
+        title
+            .element(snippet)
+            .elements(caller_snippet)
+            .elements(caller_note)
+            .elements(notes)
+    }
+
+    /// The fallback when the source file can't be read: each failure's
+    /// location and label.
+    fn render_listing(&self, f: &mut dyn fmt::Write, styled: bool) -> fmt::Result {
+        write!(f, "{}:", self.title())?;
+        for error in &self.errors {
+            write!(
+                f,
+                "\n  --> {}:{}\n  {}",
+                self.rel_path,
+                error.error_node.line_start,
+                error_label(error)
+            )?;
+            if let Some(summary) = &error.summary {
+                write!(f, "\n{summary}")?;
+            }
+            if let Some(diff) = &error.diff {
+                write!(f, "\n{}", diff.render(styled))?;
+            }
+            for nested in &error.nested {
+                write!(f, "\n\n")?;
+                nested.render_snippets(f)?;
+            }
+        }
+        if let Some(caller) = self.caller {
+            write!(f, "\n  called from {caller}")?;
+        }
         Ok(())
     }
 }
 
+/// Quote and escape `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// `Debug` renders the same report as `Display` so that `unwrap()` on a
+// `check_struct!` result, or a `Result`-returning test, prints the snippet
+// rather than the internal node tree.
+impl fmt::Debug for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ErrorReport {}
+
 impl fmt::Debug for PatternNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PatternNode")
             .field("kind", &self.kind)
             .field("parent", &self.parent.map(|_| "<parent>"))
+            .field("step", &self.step)
             .field("line_start", &self.line_start)
             .field("col_start", &self.col_start)
             .field("line_end", &self.line_end)
@@ -410,10 +1421,41 @@ impl fmt::Display for PatternNode {
             NodeKind::Simple { value } => write!(f, "{}", value),
             NodeKind::Comparison { op, value } => write!(f, "{} {}", op.as_str(), value),
             NodeKind::Range { pattern } => write!(f, "{}", pattern),
+            NodeKind::Approx {
+                expected,
+                tolerance,
+                relative,
+            } => {
+                let percent = if *relative { "%" } else { "" };
+                write!(f, "~ {} +- {}{}", expected, tolerance, percent)
+            }
             NodeKind::Regex { pattern } => write!(f, "=~ {}", pattern),
             NodeKind::Like { expr } => write!(f, "=~ {}", expr),
             NodeKind::Wildcard => write!(f, "_"),
             NodeKind::Closure { closure } => write!(f, "{}", closure),
+            NodeKind::Quantifier { kind, inner } => write!(f, "[{}: {}]", kind.as_str(), inner),
+            NodeKind::Or { alternatives } => {
+                for (i, alt) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", alt)?;
+                }
+                Ok(())
+            }
+            NodeKind::Not { inner } => match inner.kind {
+                NodeKind::Or { .. } | NodeKind::And { .. } => write!(f, "!({})", inner),
+                _ => write!(f, "!{}", inner),
+            },
+            NodeKind::And { conjuncts } => {
+                for (i, conjunct) in conjuncts.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " && ")?;
+                    }
+                    write!(f, "{}", conjunct)?;
+                }
+                Ok(())
+            }
         }
     }
 }
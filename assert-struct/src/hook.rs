@@ -0,0 +1,192 @@
+//! What happens when an `assert_struct!` assertion fails: collection by an
+//! enclosing outer assertion or [`soft`] scope, the process-wide failure hook,
+//! or a panic.
+
+use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
+
+use crate::ErrorReport;
+use crate::error::PatternNode;
+
+/// A handler for failed assertions, registered with [`set_failure_hook`].
+pub type FailureHook = dyn Fn(ErrorReport) + Send + Sync + 'static;
+
+static FAILURE_HOOK: RwLock<Option<Arc<FailureHook>>> = RwLock::new(None);
+
+thread_local! {
+    /// Reports collected by the [`soft`] and [`NestedScope`] scopes of this
+    /// thread, innermost last.
+    static SCOPES: RefCell<Vec<Vec<ErrorReport>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Register a handler that `assert_struct!` and `debug_assert_struct!` call
+/// with the report when an assertion fails, replacing any previous one.
+///
+/// By default a failed assertion panics with the rendered report. A hook can
+/// log or record the report, attach context and panic itself, for example
+/// with [`std::panic::panic_any`] and a payload type a harness downcasts. If
+/// the hook returns, execution continues after the assertion, which makes the
+/// failure a soft one.
+///
+/// The hook is shared by all threads, so tests that register one should not
+/// run in parallel with tests expecting the default panic. Failures inside
+/// [`soft`] are collected there instead of reaching the hook.
+///
+/// ```rust
+/// # use assert_struct::assert_struct;
+/// # use std::sync::Mutex;
+/// static FAILED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// assert_struct::set_failure_hook(|report| {
+///     FAILED.lock().unwrap().extend(report.failures().map(|f| f.to_string()));
+/// });
+///
+/// let status = 500;
+/// assert_struct!(status, 200);
+/// assert_eq!(*FAILED.lock().unwrap(), ["status: got 500"]);
+/// # assert_struct::take_failure_hook();
+/// ```
+pub fn set_failure_hook<F>(hook: F)
+where
+    F: Fn(ErrorReport) + Send + Sync + 'static,
+{
+    let hook: Arc<FailureHook> = Arc::new(hook);
+    *FAILURE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Unregister the hook set by [`set_failure_hook`], restoring the default
+/// panic, and return it.
+pub fn take_failure_hook() -> Option<Arc<FailureHook>> {
+    FAILURE_HOOK
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+}
+
+/// Run `f`, collecting the reports of `assert_struct!` and
+/// `debug_assert_struct!` failures instead of stopping at the first, then
+/// panic once with all of them.
+///
+/// Each failed assertion is recorded and execution continues after it. When
+/// `f` returns, the collected reports are rendered one after the other, as
+/// they would be on their own, in a single panic. Nothing happens if every
+/// assertion passed, and `f`'s result is returned.
+///
+/// Only assertions on the current thread are collected. A `soft` call inside
+/// another, or inside an outer assertion's closure pattern, passes its reports
+/// on to the outer one rather than panicking.
+///
+/// ```rust,should_panic
+/// # use assert_struct::assert_struct;
+/// # #[derive(Debug)]
+/// # struct Response { status: u16, body: &'static str }
+/// let health = Response { status: 503, body: "" };
+/// let metrics = Response { status: 200, body: "" };
+///
+/// // Panics once, showing both failures
+/// assert_struct::soft(|| {
+///     assert_struct!(health, Response { status: 200, .. });
+///     assert_struct!(metrics, Response { body: != "", .. });
+/// });
+/// ```
+#[track_caller]
+pub fn soft<R>(f: impl FnOnce() -> R) -> R {
+    // Pops the scope even if `f` panics
+    struct Scope;
+
+    impl Drop for Scope {
+        fn drop(&mut self) {
+            SCOPES.with_borrow_mut(|scopes| scopes.pop());
+        }
+    }
+
+    SCOPES.with_borrow_mut(|scopes| scopes.push(Vec::new()));
+    let scope = Scope;
+    let result = f();
+    let reports =
+        SCOPES.with_borrow_mut(|scopes| scopes.last_mut().map(std::mem::take).unwrap_or_default());
+    drop(scope);
+
+    if reports.is_empty() {
+        return result;
+    }
+
+    let reports = SCOPES.with_borrow_mut(|scopes| match scopes.last_mut() {
+        Some(outer) => {
+            outer.extend(reports);
+            None
+        }
+        None => Some(reports),
+    });
+    if let Some(reports) = reports {
+        let mut message = String::new();
+        for report in &reports {
+            let rendered = report.to_string();
+            // Line-based formats end each report with a newline. Snippets
+            // don't, and are separated by a blank line like compiler errors.
+            if !message.is_empty() && !message.ends_with('\n') {
+                message.push_str("\n\n");
+            }
+            message.push_str(&rendered);
+        }
+        panic!("{}", message);
+    }
+
+    result
+}
+
+/// Collects the reports of assertions that fail while a closure pattern is
+/// evaluated, so the outer assertion reports them under that pattern instead
+/// of the inner one panicking.
+pub struct NestedScope(());
+
+impl NestedScope {
+    pub fn enter() -> Self {
+        SCOPES.with_borrow_mut(|scopes| scopes.push(Vec::new()));
+        NestedScope(())
+    }
+
+    /// Leave the scope, recording the collected reports on `node` of the
+    /// outer `report`.
+    pub fn exit(self, report: &mut ErrorReport, node: &'static PatternNode) {
+        let nested = SCOPES
+            .with_borrow_mut(|scopes| scopes.last_mut().map(std::mem::take).unwrap_or_default());
+        drop(self);
+        if !nested.is_empty() {
+            report.push_nested(node, nested);
+        }
+    }
+}
+
+// Pops the scope even if the closure panics
+impl Drop for NestedScope {
+    fn drop(&mut self) {
+        SCOPES.with_borrow_mut(|scopes| scopes.pop());
+    }
+}
+
+/// Hand a failed assertion's report to the enclosing outer assertion or
+/// [`soft`] scope, the registered hook, or panic with it.
+#[track_caller]
+pub fn fail(report: ErrorReport) {
+    let report = SCOPES.with_borrow_mut(|scopes| match scopes.last_mut() {
+        Some(scope) => {
+            scope.push(report);
+            None
+        }
+        None => Some(report),
+    });
+    let Some(report) = report else {
+        return;
+    };
+
+    // Clone the hook out of the lock so it can replace itself
+    let hook = FAILURE_HOOK
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    match hook {
+        Some(hook) => hook(report),
+        None => panic!("{}", report),
+    }
+}
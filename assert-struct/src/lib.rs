@@ -20,11 +20,14 @@
 //!   - [Comparison Operators](#comparison-operators)
 //!   - [Equality Operators](#equality-operators)
 //!   - [Range Patterns](#range-patterns)
+//!   - [Approximate Float Equality](#approximate-float-equality)
 //!   - [Regex Patterns](#regex-patterns)
 //!   - [Method Call Patterns](#method-call-patterns)
+//!   - [Combining Patterns](#combining-patterns)
 //! - [Data Types](#data-types)
 //!   - [Collections (Vec/Slice)](#collections-vecslice)
 //!   - [Set Patterns](#set-patterns)
+//!   - [Quantifiers](#quantifiers)
 //!   - [Maps (HashMap/BTreeMap)](#maps-hashmapbtreemap)
 //!   - [Tuples](#tuples)
 //!   - [Enums (Option/Result/Custom)](#enums-optionresultcustom)
@@ -32,6 +35,9 @@
 //! - [Error Messages](#error-messages)
 //! - [Advanced Usage](#advanced-usage)
 //!   - [Dynamic Value Types](#dynamic-value-types)
+//!   - [Non-Panicking Checks](#non-panicking-checks)
+//!   - [Patterns as Predicates](#patterns-as-predicates)
+//!   - [Debug-Only Assertions](#debug-only-assertions)
 //!
 //! # Quick Start
 //!
@@ -225,6 +231,28 @@
 //! });
 //! ```
 //!
+//! ## Approximate Float Equality
+//!
+//! Compare `f32`/`f64` values within a tolerance with `~ expected +- tolerance`.
+//! A trailing `%` makes the tolerance relative to the expected value:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Metrics { ratio: f64, latency_ms: f32, samples: Vec<f64> }
+//! # let metrics = Metrics { ratio: 0.1 + 0.2, latency_ms: 12.4, samples: vec![1.0, 2.0000001] };
+//! assert_struct!(metrics, Metrics {
+//!     ratio: ~ 0.3 +- 1e-9,          // |actual - 0.3| <= 1e-9
+//!     latency_ms: ~ 12.5 +- 1%,      // within 1% of 12.5
+//!     samples: [~ 1.0 +- 1e-6, ~ 2.0 +- 1e-6],
+//! });
+//! ```
+//!
+//! The expected value is rounded to the field's type first, so `~ 0.3 +- 1e-12`
+//! matches `0.3_f32`. Only zero is within a percentage of zero. Failures show
+//! the expected value, the tolerance and the actual difference. NaN never
+//! matches.
+//!
 //! ## Regex Patterns
 //!
 //! Match string patterns with regular expressions (requires `regex` feature, enabled by default):
@@ -295,6 +323,69 @@
 //! });
 //! ```
 //!
+//! ## Combining Patterns
+//!
+//! Separate alternatives with `|` to accept any of them. Each alternative can be
+//! any pattern, and alternation works anywhere a pattern is expected, including
+//! inside slices, sets, maps and enum arguments:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # enum Status { Active, Pending { since: u32 }, Closed }
+//! # #[derive(Debug)]
+//! # struct Response { code: u16, status: Status, tags: Vec<&'static str> }
+//! # let response = Response { code: 204, status: Status::Pending { since: 3 }, tags: vec!["beta"] };
+//! assert_struct!(response, Response {
+//!     code: 200 | 204 | 300..400,
+//!     status: Status::Active | Status::Pending { .. },
+//!     tags: ["beta" | "stable"],
+//! });
+//! ```
+//!
+//! On failure, the report says that none of the alternatives matched and
+//! underlines each one. A top-level `|` always separates alternatives, so
+//! parenthesize bitwise-or values: `mode: == (READ | WRITE)`.
+//!
+//! Prefix any pattern with `!` (or wrap it in `not(...)`) to require that it
+//! does *not* match. `!` applies to a single alternative, so use parentheses to
+//! negate an alternation:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # enum Status { Active, Suspended { reason: String }, Closed }
+//! # #[derive(Debug)]
+//! # struct Account { status: Status, balance: i64, roles: Vec<&'static str> }
+//! # let account = Account { status: Status::Active, balance: 10, roles: vec!["member"] };
+//! assert_struct!(account, Account {
+//!     status: !(Status::Suspended { .. } | Status::Closed),
+//!     balance: not(< 0),
+//!     roles: !#("admin", ..),   // no element is "admin"
+//! });
+//! ```
+//!
+//! `!=~ r"..."` is shorthand for `!(=~ r"...")`, and `!=` keeps its meaning as
+//! the inequality operator.
+//!
+//! Join patterns with `&&` to require all of them on the same value, instead
+//! of repeating the field. Each failing conjunct is reported on its own:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Record { id: String, score: i32, tags: Vec<&'static str> }
+//! # let record = Record { id: "id-42".to_string(), score: 73, tags: vec!["alpha", "beta"] };
+//! assert_struct!(record, Record {
+//!     id: != "" && != "id-0",
+//!     score: > 0 && < 100,
+//!     tags: ["alpha", ..] && #("beta", ..),
+//! });
+//! ```
+//!
+//! `&&` binds tighter than `|`, so `> 0 && < 10 | 100` accepts 1 through 9 or
+//! exactly 100. As with `|`, parenthesize a logical-and value: `== (a && b)`.
+//!
 //! # Data Types
 //!
 //! ## Collections (Vec/Slice)
@@ -342,6 +433,10 @@
 //! });
 //! ```
 //!
+//! A slice of the wrong length is reported as such (`expected 3 elements, got 5`,
+//! or `at least` with `..`). Element failures point at the element's own pattern and
+//! are labelled with its index in the value, e.g. `items[2].name: got "pad"`.
+//!
 //! ## Set Patterns
 //!
 //! Use `#(...)` to assert that a collection contains elements matching the given patterns,
@@ -405,6 +500,9 @@
 //! });
 //! ```
 //!
+//! When no assignment works, the failure highlights each pattern that was left without an
+//! element and lists the elements that came closest to matching it.
+//!
 //! Empty and wildcard set patterns:
 //!
 //! ```rust
@@ -418,10 +516,37 @@
 //! });
 //! ```
 //!
+//! ## Quantifiers
+//!
+//! Apply one pattern to every element of a collection of any length with
+//! `[all: pattern]`, `[any: pattern]` or `[none: pattern]`. They work on
+//! anything whose reference is iterable:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Item { name: String, price: u32 }
+//! # #[derive(Debug)]
+//! # struct Cart { items: Vec<Item>, coupons: Vec<Option<String>> }
+//! # let cart = Cart {
+//! #     items: vec![Item { name: "apple".to_string(), price: 3 }],
+//! #     coupons: vec![None, Some("SAVE5".to_string())],
+//! # };
+//! assert_struct!(cart, Cart {
+//!     items: [all: Item { price: > 0, .. }],   // every element matches
+//!     coupons: [any: Some(_)],                 // at least one matches
+//!     items: [none: { name: "" }],             // no element matches
+//! });
+//! ```
+//!
+//! Failures name the offending elements instead of printing the whole
+//! collection, e.g. `items[3].price: got 0`.
+//!
 //!
 //! ## Maps (HashMap/BTreeMap)
 //!
-//! Pattern matching for map-like structures using duck typing (works with any type that has `len()` and `get()` methods):
+//! Pattern matching for map-like structures using duck typing (works with any type that has `len()` and `get()` methods,
+//! plus `keys()` for exact patterns):
 //!
 //! ```rust
 //! # use assert_struct::assert_struct;
@@ -504,6 +629,12 @@
 //! });
 //! ```
 //!
+//! When the keys of an exact pattern differ from the map's, the failure lists the missing
+//! keys, the unexpected keys and the keys whose values didn't match. To find the unexpected
+//! ones, an exact pattern needs a `keys()` method returning an iterator of `&K`, with `K`
+//! comparable to the pattern's keys by `==` and implementing `Debug`. Partial patterns only
+//! use `get()`. A key written twice is one entry, with both value patterns applied to it.
+//!
 //! ## Tuples
 //!
 //! Full support for multi-field tuples:
@@ -710,6 +841,11 @@
 //!
 //! When assertions fail, `assert-struct` provides detailed, actionable error messages:
 //!
+//! The snippet is read from the source file at runtime. The macro also embeds the text of
+//! its own invocation, so the snippet still renders when the file isn't there, as in
+//! packaged test binaries, containers without sources, or under remapped paths. Comments
+//! inside the invocation are not kept in that case.
+//!
 //! ## Basic Mismatch
 //!
 //! ```rust,should_panic
@@ -722,12 +858,11 @@
 //!     age: 25,
 //! });
 //! // Error output:
-//! // assert_struct! failed:
-//! //
-//! // value mismatch:
-//! //   --> `user.name` (src/lib.rs:456)
-//! //   actual: "Alice"
-//! //   expected: "Bob"
+//! // error: assert_struct! failed
+//! //   --> src/lib.rs:5:11
+//! //    |
+//! //  5 |     name: "Bob",  // This will fail
+//! //    |           ^^^^^ user.name: got "Alice"
 //! ```
 //!
 //! ## Comparison Failure
@@ -741,18 +876,202 @@
 //!     score: > 100,  // This will fail
 //! });
 //! // Error output:
-//! // assert_struct! failed:
-//! //
-//! // comparison mismatch:
-//! //   --> `stats.score` (src/lib.rs:469)
-//! //   actual: 50
-//! //   expected: > 100
+//! // error: assert_struct! failed
+//! //   --> src/lib.rs:5:12
+//! //    |
+//! //  5 |     score: > 100,  // This will fail
+//! //    |            ^^^^^ stats.score: got 50
 //! ```
 //!
 //! ## Nested Field Errors
 //!
 //! Error messages show the exact path to the failing field, even in deeply nested structures.
-//! Method calls are also shown in the field path for clear debugging.
+//! The path starts at the asserted expression and includes field operations, element
+//! indices and map keys, e.g. `order.customer.address.city`, `response.items.len()` or
+//! `config.settings["port"]`. It is part of every label, and
+//! [`Failure::path`](crate::Failure::path) returns it on its own.
+//!
+//! ## Structural Diffs
+//!
+//! When `== expected` fails on a value that pretty-prints over several lines, such as a
+//! config struct, the report includes a diff of the `{:#?}` output of both sides. Only the
+//! changed lines are shown, under the fields and collections that contain them:
+//!
+//! ```rust,should_panic
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug, PartialEq, Clone)]
+//! # struct Server { host: String, port: u16 }
+//! # #[derive(Debug, PartialEq, Clone)]
+//! # struct Config { name: String, server: Server }
+//! # #[derive(Debug)]
+//! # struct Deployment { config: Config }
+//! # let expected = Config { name: "api".into(), server: Server { host: "localhost".into(), port: 8080 } };
+//! # let deployment = Deployment { config: Config { server: Server { port: 9090, ..expected.server.clone() }, ..expected.clone() } };
+//! assert_struct!(deployment, Deployment {
+//!     config: == expected,
+//! });
+//! // Error output:
+//! // error: assert_struct! failed
+//! //    |
+//! // 12 |     config: == expected,
+//! //    |             ^^^^^^^^^^^ deployment.config: expected expected, got a different value (see diff)
+//! //    |
+//! //    = note: diff (- expected, + actual):
+//! //        Config {
+//! //            ...
+//! //            server: Server {
+//! //                ...
+//! //      -         port: 8080,
+//! //      +         port: 9090,
+//! //        ...
+//! ```
+//!
+//! String mismatches, from string literal patterns or `==` between two strings, get a text
+//! diff instead: long single lines are compared character by character with the changed
+//! words marked, and multiline text such as rendered templates gets a unified line diff.
+//! Like the rest of the report, diffs are colored only when writing to a terminal.
+//!
+//! ## Assertion Helpers
+//!
+//! Assertions wrapped in a helper function point at the helper. Mark the helper
+//! `#[track_caller]` and the report also shows the line that called it:
+//!
+//! ```rust,should_panic
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct User { name: String, age: u32 }
+//! #[track_caller]
+//! fn assert_adult(user: &User) {
+//!     assert_struct!(user, User { age: >= 18, .. });
+//! }
+//!
+//! let user = User { name: "Ada".to_string(), age: 12 };
+//! assert_adult(&user);
+//! // Error output:
+//! // error: assert_struct! failed
+//! //   --> src/lib.rs:4:38
+//! //    |
+//! //  4 |     assert_struct!(user, User { age: >= 18, .. });
+//! //    |                                      ^^^^^ user.age: got 12
+//! // ...
+//! //  8 | assert_adult(&user);
+//! //    | -------------------- called from here
+//! ```
+//!
+//! [`ErrorReport::caller`] returns the calling location.
+//!
+//! ## Nested Assertions
+//!
+//! A closure pattern may run an `assert_struct!` of its own, directly or through a helper
+//! it calls. Its failure doesn't panic from inside the closure: the outer report gets a
+//! `nested assertion failed` failure at that pattern, followed by the inner report:
+//!
+//! ```rust,should_panic
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Address { zip: String }
+//! # #[derive(Debug)]
+//! # struct Customer { name: String, address: Address }
+//! impl Address {
+//!     fn validate(&self) -> bool {
+//!         assert_struct!(self, Address { zip.len(): 5 });
+//!         true
+//!     }
+//! }
+//!
+//! let customer = Customer {
+//!     name: "Ada".to_string(),
+//!     address: Address { zip: "1011".to_string() },
+//! };
+//! assert_struct!(customer, Customer {
+//!     name: "Ada",
+//!     address: |a: &Address| a.validate(),
+//! });
+//! // Error output:
+//! // error: assert_struct! failed
+//! //    |
+//! // 14 |     address: |a: &Address| a.validate(),
+//! //    |              ^ customer.address: nested assertion failed
+//! //    |
+//! // note: nested assert_struct! failed
+//! //    |
+//! //  3 |         assert_struct!(self, Address { zip.len(): 5 });
+//! //    |                                                   ^ self.zip.len(): got 4
+//! ```
+//!
+//! [`Failure::nested`] returns the inner reports. Since the inner assertion returns
+//! instead of panicking, the closure keeps running after it fails; the pattern fails
+//! whatever the closure returns. Only closure patterns collect inner failures: an
+//! assertion inside a method called in a field path (`address.validate(): true`)
+//! panics as it would anywhere else.
+//!
+//! ## Custom Messages
+//!
+//! Like `assert_eq!`, trailing `format!` arguments add a message to the report title.
+//! They are only evaluated when the assertion fails:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Response { status: u16 }
+//! # let responses = vec![Response { status: 200 }];
+//! for (id, response) in responses.iter().enumerate() {
+//!     assert_struct!(response, Response { status: 200 }, "request {} failed", id);
+//! }
+//! // Error output:
+//! // error: assert_struct! failed: request 3 failed
+//! ```
+//!
+//! ## JSON Output
+//!
+//! For CI tooling, setting `ASSERT_STRUCT_FORMAT=json` in the environment renders reports
+//! as JSON Lines instead, one object per failure. The human report stays the default, and
+//! [`ErrorReport::to_json`] renders JSON regardless of the variable:
+//!
+//! ```text
+//! {"file":"tests/users.rs","line":12,"column":10,"end_line":12,"end_column":13,"path":"user.age","kind":"Comparison","pattern":"< 18","actual":"30","expected":null,"message":null}
+//! ```
+//!
+//! `ASSERT_STRUCT_FORMAT=github` renders one GitHub Actions `::error` command per failure
+//! instead, so failures show up as annotations on the failing pattern in the pull request
+//! diff. The [`render`] module has these renderers; [`ErrorReport::render`] uses any of them,
+//! or your own [`render::Render`] implementation, regardless of the variable.
+//!
+//! ## Soft Assertions
+//!
+//! [`soft`] keeps a test going past failed assertions and panics once at the end with
+//! every report, so one run shows every broken invariant, even across helper functions in
+//! other files:
+//!
+//! ```rust,should_panic
+//! # use assert_struct::assert_struct;
+//! # #[derive(Debug)]
+//! # struct Service { healthy: bool, latency_ms: u32 }
+//! # let services = vec![Service { healthy: false, latency_ms: 900 }];
+//! assert_struct::soft(|| {
+//!     for service in &services {
+//!         assert_struct!(service, Service { healthy: true, latency_ms: < 100 });
+//!         assert_struct!(service.latency_ms, < 500);
+//!     }
+//! });
+//! ```
+//!
+//! ## Failure Hook
+//!
+//! A failed `assert_struct!` panics with the rendered report. [`set_failure_hook`]
+//! registers a process-wide handler that receives the report instead, to log it, panic
+//! with a payload your harness downcasts, or record it and carry on:
+//!
+//! ```rust
+//! # use assert_struct::assert_struct;
+//! assert_struct::set_failure_hook(|report| {
+//!     eprintln!("{} assertion failures", report.len());
+//!     std::panic::panic_any(report);
+//! });
+//! # assert_struct::take_failure_hook();
+//! ```
+//!
+//! [`take_failure_hook`] restores the default panic.
 //!
 //! # Advanced Usage
 //!
@@ -845,6 +1164,103 @@
 //! assert_eq!(complex.data.as_ref().unwrap().len(), 3);
 //! ```
 //!
+//! ## Non-Panicking Checks
+//!
+//! [`check_struct!`] takes the same arguments as [`assert_struct!`] but returns
+//! `Result<(), ErrorReport>` instead of panicking. This is useful in tests that
+//! return `Result`, in property-testing bodies, and in runtime validators:
+//!
+//! ```rust
+//! # use assert_struct::{check_struct, ErrorReport};
+//! # #[derive(Debug)]
+//! # struct Response { status: u16, body: String }
+//! fn validate(response: &Response) -> Result<(), ErrorReport> {
+//!     check_struct!(response, Response {
+//!         status: 200..300,
+//!         body: != "",
+//!     })
+//! }
+//!
+//! let bad = Response { status: 500, body: String::new() };
+//! let report = validate(&bad).unwrap_err();
+//! assert_eq!(report.len(), 2);
+//!
+//! for failure in report.failures() {
+//!     println!("{}: {}", failure.location(), failure);
+//! }
+//! ```
+//!
+//! The report's `Display` output is the same annotated snippet that
+//! `assert_struct!` panics with.
+//!
+//! ## Inspecting Failures
+//!
+//! Test harnesses and tools can walk a report's failures instead of parsing its output.
+//! [`ErrorReport::failures`] yields a [`Failure`] per mismatch, with the field path, the
+//! [`PatternKind`], the pattern's text, its source [`Location`], and the actual and expected
+//! values:
+//!
+//! ```rust
+//! # use assert_struct::{check_struct, PatternKind};
+//! # #[derive(Debug)]
+//! # struct User { name: String, age: u32 }
+//! let user = User { name: "Alice".to_string(), age: 30 };
+//! let report = check_struct!(user, User { name: "Alice", age: < 18 }).unwrap_err();
+//!
+//! let failure = report.failures().next().unwrap();
+//! assert_eq!(failure.path(), "user.age");
+//! assert_eq!(failure.kind(), PatternKind::Comparison);
+//! assert_eq!(failure.pattern(), "< 18");
+//! assert_eq!(failure.actual(), "30");
+//! assert_eq!(failure.expected(), None);
+//! println!("{}", failure.location()); // file:line:column
+//! ```
+//!
+//! These types, together with the report's `Display`, [`ErrorReport::render`] and
+//! [`ErrorReport::to_json`], are the public API for failures and follow semver. The types
+//! and methods hidden from this documentation exist for the generated code and may change
+//! in any release.
+//!
+//! ## Patterns as Predicates
+//!
+//! [`matches_struct!`] evaluates a pattern to a `bool`, and [`pattern!`] turns a
+//! pattern into an `impl Fn(&T) -> bool` closure for iterator adapters. Neither
+//! panics or renders a report:
+//!
+//! ```rust
+//! # use assert_struct::{matches_struct, pattern};
+//! # #[derive(Debug)]
+//! # struct Order { id: u32, total: u32, status: &'static str }
+//! let orders = vec![
+//!     Order { id: 1, total: 250, status: "shipped" },
+//!     Order { id: 2, total: 0, status: "cancelled" },
+//! ];
+//!
+//! assert!(matches_struct!(orders[0], Order { total: > 100, .. }));
+//! assert!(orders.iter().any(pattern!({ status: "cancelled" })));
+//! assert_eq!(orders.iter().position(pattern!({ total: 0 })), Some(1));
+//! ```
+//!
+//! ## Debug-Only Assertions
+//!
+//! [`debug_assert_struct!`] is to [`assert_struct!`] what `debug_assert!` is to
+//! `assert!`: it checks internal invariants in debug builds and is skipped
+//! when `debug_assertions` are off. In release builds the assertion is still
+//! type-checked, but the value expression is not evaluated, so it must not have
+//! side effects you rely on.
+//!
+//! ```rust
+//! # use assert_struct::debug_assert_struct;
+//! # #[derive(Debug)]
+//! # struct Pool { idle: usize, active: usize, max: usize }
+//! fn release(pool: &mut Pool) {
+//!     pool.active -= 1;
+//!     pool.idle += 1;
+//!     debug_assert_struct!(pool, Pool { active: < 10, idle: <= 10, .. });
+//! }
+//! # release(&mut Pool { idle: 0, active: 1, max: 10 });
+//! ```
+//!
 //! ## Real-World Testing Patterns
 //!
 //! See the [examples directory](../../examples/) for comprehensive real-world examples including:
@@ -855,17 +1271,29 @@
 //!
 //! For complete specification details, see the [`assert_struct!`] macro documentation.
 
-// Re-export the procedural macro
-pub use assert_struct_macros::assert_struct;
+// Re-export the procedural macros
+pub use assert_struct_macros::{
+    assert_struct, check_struct, debug_assert_struct, matches_struct, pattern,
+};
 
+mod diff;
 // Error handling module
 #[doc(hidden)]
 pub mod error;
+mod hook;
+pub mod render;
+
+pub use error::{ErrorReport, Failure, Failures, Location, PatternKind};
+pub use hook::{FailureHook, set_failure_hook, soft, take_failure_hook};
 
 // Hidden module for macro support functions
 #[doc(hidden)]
 pub mod __macro_support {
-    pub use crate::error::{ComparisonOp, ErrorReport, NodeKind, PatternNode, PlainOutputGuard};
+    pub use crate::error::{
+        ComparisonOp, EmbeddedSource, ErrorReport, IgnoreSourceFilesGuard, NodeKind, PathStep,
+        PatternNode, PlainOutputGuard, QuantifierKind,
+    };
+    pub use crate::hook::{NestedScope, fail};
 
     // Re-export regex types for macro expansion when regex feature is enabled
     #[cfg(feature = "regex")]
@@ -880,17 +1308,248 @@ pub mod __macro_support {
         predicate(value)
     }
 
+    /// Returns `expected` with the type of `actual`, which may be behind more
+    /// references. Lets a unit variant of a generic enum used as a value infer
+    /// its type parameters from the asserted value.
+    #[inline]
+    pub fn typed_like<'a, T>(expected: &'a T, _actual: &T) -> &'a T {
+        expected
+    }
+
+    /// Wraps the expected value of an equality pattern so it can be pretty-printed
+    /// when its type implements `Debug`, and skipped otherwise. Call
+    /// `(&&MaybeDebug(value)).pretty_debug()` with both [`DebugIfAvailable`] and
+    /// [`NoDebug`] in scope; method resolution picks the first that applies.
+    pub struct MaybeDebug<'a, T: ?Sized>(pub &'a T);
+
+    /// Chosen for `&&MaybeDebug<T>` when `T: Debug`.
+    pub trait DebugIfAvailable {
+        fn pretty_debug(&self) -> Option<String>;
+    }
+
+    impl<T: std::fmt::Debug + ?Sized> DebugIfAvailable for &MaybeDebug<'_, T> {
+        fn pretty_debug(&self) -> Option<String> {
+            Some(format!("{:#?}", self.0))
+        }
+    }
+
+    /// Fallback reached by auto-deref when `T` does not implement `Debug`.
+    pub trait NoDebug {
+        fn pretty_debug(&self) -> Option<String>;
+    }
+
+    impl<T: ?Sized> NoDebug for MaybeDebug<'_, T> {
+        fn pretty_debug(&self) -> Option<String> {
+            None
+        }
+    }
+
+    /// Like [`MaybeDebug`], for reading a value as text when it implements
+    /// `AsRef<str>`, so string mismatches get a text diff. Call
+    /// `(&&MaybeText(value)).text()` with [`TextIfAvailable`] and [`NoText`] in scope.
+    pub struct MaybeText<'a, T: ?Sized>(pub &'a T);
+
+    /// Chosen for `&&MaybeText<T>` when `T: AsRef<str>`.
+    pub trait TextIfAvailable {
+        fn text(&self) -> Option<String>;
+    }
+
+    impl<T: AsRef<str> + ?Sized> TextIfAvailable for &MaybeText<'_, T> {
+        fn text(&self) -> Option<String> {
+            Some(self.0.as_ref().to_string())
+        }
+    }
+
+    /// Fallback reached by auto-deref when `T` is not text.
+    pub trait NoText {
+        fn text(&self) -> Option<String>;
+    }
+
+    impl<T: ?Sized> NoText for MaybeText<'_, T> {
+        fn text(&self) -> Option<String> {
+            None
+        }
+    }
+
+    /// Float types accepted by the approximate equality pattern `~ x +- tol`.
+    pub trait ApproxFloat {
+        fn to_f64(&self) -> f64;
+
+        /// Round `value` to the precision of this type, so that an `f64`
+        /// literal compares equal to the same literal written as this type.
+        fn round_f64(&self, value: f64) -> f64;
+    }
+
+    impl ApproxFloat for f32 {
+        fn to_f64(&self) -> f64 {
+            f64::from(*self)
+        }
+
+        fn round_f64(&self, value: f64) -> f64 {
+            f64::from(value as f32)
+        }
+    }
+
+    impl ApproxFloat for f64 {
+        fn to_f64(&self) -> f64 {
+            *self
+        }
+
+        fn round_f64(&self, value: f64) -> f64 {
+            value
+        }
+    }
+
+    impl<T: ApproxFloat + ?Sized> ApproxFloat for &T {
+        fn to_f64(&self) -> f64 {
+            (**self).to_f64()
+        }
+
+        fn round_f64(&self, value: f64) -> f64 {
+            (**self).round_f64(value)
+        }
+    }
+
+    /// Runtime helper for the approximate equality pattern `~ expected +- tolerance`.
+    ///
+    /// `expected` is rounded to the precision of the value's type first. With
+    /// `relative`, `tolerance` is a percentage of `expected`, so only zero is
+    /// within any percentage of zero. NaN never matches. On failure, pushes one
+    /// error carrying the actual difference.
+    pub fn approx_match<T: ApproxFloat + std::fmt::Debug + ?Sized>(
+        actual: &T,
+        expected: f64,
+        tolerance: f64,
+        relative: bool,
+        report: &mut ErrorReport,
+        node: &'static PatternNode,
+    ) {
+        let expected = actual.round_f64(expected);
+        let diff = (actual.to_f64() - expected).abs();
+        let allowed = if relative {
+            tolerance / 100.0 * expected.abs()
+        } else {
+            tolerance
+        };
+
+        // Written so that a NaN difference fails
+        if diff <= allowed {
+            return;
+        }
+        if !report.records_values() {
+            report.push_unformatted(node);
+            return;
+        }
+
+        let (expected_str, note) = if relative && expected == 0.0 {
+            (
+                format!("{:?} ± {:?}%", expected, tolerance),
+                format!("difference {:?}, and a percentage of 0 is 0", diff),
+            )
+        } else if relative {
+            let percent = diff / expected.abs() * 100.0;
+            (
+                format!("{:?} ± {:?}%", expected, tolerance),
+                format!("difference {:?}, {:.3}%", diff, percent),
+            )
+        } else {
+            (
+                format!("{:?} ± {:?}", expected, tolerance),
+                format!("difference {:?}", diff),
+            )
+        };
+        report.push_with_note(node, format!("{:?}", actual), Some(expected_str), note);
+    }
+
+    /// Runtime helper reporting a slice pattern `[...]` whose length doesn't fit
+    /// the value, e.g. `expected 3 elements, got 5`.
+    pub fn slice_length_mismatch<T: std::fmt::Debug>(
+        actual: &[T],
+        report: &mut ErrorReport,
+        node: &'static PatternNode,
+    ) {
+        if !report.records_values() {
+            report.push_unformatted(node);
+            return;
+        }
+
+        let (len, rest) = match &node.kind {
+            NodeKind::Slice { items, rest } => (items.len(), *rest),
+            _ => (0, true),
+        };
+        let elements = |n: usize| if n == 1 { "element" } else { "elements" };
+        let at_least = if rest { "at least " } else { "" };
+        let label = format!(
+            "expected {}{} {}, got {}",
+            at_least,
+            len,
+            elements(len),
+            actual.len()
+        );
+        report.push_with_label(node, format!("{:?}", actual), label);
+    }
+
+    /// Runtime helper reporting an exact map pattern `#{...}` whose keys differ
+    /// from the value's. Pushes one failure on the map, labelled with the
+    /// counts and carrying a summary of the missing, unexpected (sorted) and
+    /// mismatched keys.
+    pub fn map_keys_mismatch<K: std::fmt::Debug>(
+        report: &mut ErrorReport,
+        node: &'static PatternNode,
+        missing: &[&str],
+        unexpected: &[K],
+        mismatched: &[&str],
+    ) {
+        if !report.records_values() {
+            report.push_unformatted(node);
+            return;
+        }
+
+        let mut unexpected: Vec<String> = unexpected.iter().map(|k| format!("{:?}", k)).collect();
+        unexpected.sort();
+
+        let count = |n: usize, what: &str| {
+            let s = if n == 1 { "" } else { "s" };
+            format!("{} {} key{}", n, what, s)
+        };
+        let label = [(missing.len(), "missing"), (unexpected.len(), "unexpected")]
+            .into_iter()
+            .filter(|&(n, _)| n > 0)
+            .map(|(n, what)| count(n, what))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut summary = String::from("map keys:");
+        for (heading, keys) in [
+            ("missing", missing.join(", ")),
+            ("unexpected", unexpected.join(", ")),
+            ("mismatched values", mismatched.join(", ")),
+        ] {
+            if !keys.is_empty() {
+                summary.push_str(&format!("\n  {}: {}", heading, keys));
+            }
+        }
+
+        report.push_with_label(node, format!("[{}]", unexpected.join(", ")), label);
+        report.attach_summary(summary);
+    }
+
     /// Runtime helper for the set pattern `#(...)`.
     ///
-    /// Checks that `n_elements` satisfies the length constraint, then uses backtracking
-    /// to find a 1-to-1 assignment of patterns to elements. Each predicate returns `true`
-    /// if the element at the given index matches its pattern.
+    /// Checks that `n_elements` satisfies the length constraint, then looks for a
+    /// 1-to-1 assignment of patterns to elements. Each predicate checks the element at
+    /// the given index against its pattern, recording mismatches in the report it is
+    /// handed and returning it. Every predicate is evaluated at most once per element
+    /// for the assignment, which is found with Hopcroft–Karp maximum bipartite
+    /// matching, so the cost stays polynomial even when patterns overlap.
     ///
-    /// On failure, pushes exactly one error to `report`.
+    /// On a length mismatch, pushes one error for the whole set. Otherwise pushes one
+    /// error per pattern left without an element, describing the elements it came
+    /// closest to matching.
     pub fn set_match(
         n_elements: usize,
         rest: bool,
-        predicates: &[&dyn Fn(usize) -> bool],
+        predicates: &[&dyn Fn(usize, ErrorReport) -> ErrorReport],
         report: &mut ErrorReport,
         node: &'static PatternNode,
     ) {
@@ -904,6 +1563,10 @@ pub mod __macro_support {
         };
 
         if !length_ok {
+            if !report.records_values() {
+                report.push_unformatted(node);
+                return;
+            }
             let expected_str = if rest {
                 format!("at least {} element(s)", n_patterns)
             } else {
@@ -917,30 +1580,147 @@ pub mod __macro_support {
             return;
         }
 
-        // Backtracking assignment
-        let mut matched = vec![false; n_elements];
-        if !set_backtrack(predicates, &mut matched, 0) {
-            report.push(node, format!("{} element(s)", n_elements), None);
+        // Evaluate each (pattern, element) pair once, keeping the number of
+        // mismatches so unmatched patterns can point at their closest elements.
+        let mismatches: Vec<Vec<usize>> = predicates
+            .iter()
+            .map(|pred| {
+                (0..n_elements)
+                    .map(|i| pred(i, ErrorReport::new_probe()).len())
+                    .collect()
+            })
+            .collect();
+
+        // `adjacency[p]` lists the elements pattern `p` accepts
+        let adjacency: Vec<Vec<usize>> = mismatches
+            .iter()
+            .map(|row| (0..n_elements).filter(|&i| row[i] == 0).collect())
+            .collect();
+
+        let pattern_match = max_matching(&adjacency, n_elements);
+
+        let items = match &node.kind {
+            NodeKind::Set { items, .. } => *items,
+            _ => &[],
+        };
+
+        for (p, &element) in pattern_match.iter().enumerate() {
+            if element != UNMATCHED {
+                continue;
+            }
+            let Some(&item) = items.get(p) else {
+                continue;
+            };
+            if !report.records_values() {
+                report.push_unformatted(item);
+                continue;
+            }
+
+            let label = if adjacency[p].is_empty() {
+                // Re-run the closest elements to describe how they differ
+                let fewest = mismatches[p].iter().copied().min().unwrap_or(0);
+                let closest: Vec<String> = (0..n_elements)
+                    .filter(|&i| mismatches[p][i] == fewest)
+                    .take(SET_CLOSEST_SHOWN)
+                    .map(|i| {
+                        predicates[p](i, ErrorReport::new_recording_probe())
+                            .describe_element(item, i)
+                    })
+                    .collect();
+                format!("no element matched, closest: {}", closest.join(", "))
+            } else {
+                let claimed: Vec<String> =
+                    adjacency[p].iter().map(|i| format!("[{}]", i)).collect();
+                format!(
+                    "no element left to match, {} matched but each is paired with another pattern",
+                    claimed.join(", ")
+                )
+            };
+            report.push_with_label(item, format!("{} element(s)", n_elements), label);
         }
     }
 
-    fn set_backtrack(
-        predicates: &[&dyn Fn(usize) -> bool],
-        matched: &mut [bool],
-        pattern_idx: usize,
-    ) -> bool {
-        if pattern_idx == predicates.len() {
-            return true;
-        }
-        for i in 0..matched.len() {
-            if !matched[i] && predicates[pattern_idx](i) {
-                matched[i] = true;
-                if set_backtrack(predicates, matched, pattern_idx + 1) {
-                    return true;
+    /// Sentinel for a pattern or element without a partner in the matching.
+    const UNMATCHED: usize = usize::MAX;
+
+    /// How many closest elements an unmatched set pattern lists.
+    const SET_CLOSEST_SHOWN: usize = 3;
+
+    /// Maximum matching between patterns (left) and elements (right), computed
+    /// with Hopcroft–Karp. Returns the element paired with each pattern, or
+    /// [`UNMATCHED`].
+    fn max_matching(adjacency: &[Vec<usize>], n_elements: usize) -> Vec<usize> {
+        let n_patterns = adjacency.len();
+        let mut pattern_match = vec![UNMATCHED; n_patterns];
+        let mut element_match = vec![UNMATCHED; n_elements];
+        let mut dist = vec![0usize; n_patterns];
+
+        loop {
+            // Breadth-first search from every free pattern, layering the graph by
+            // alternating path length.
+            let mut queue = std::collections::VecDeque::new();
+            for p in 0..n_patterns {
+                if pattern_match[p] == UNMATCHED {
+                    dist[p] = 0;
+                    queue.push_back(p);
+                } else {
+                    dist[p] = UNMATCHED;
+                }
+            }
+
+            let mut found_free_element = false;
+            while let Some(p) = queue.pop_front() {
+                for &e in &adjacency[p] {
+                    let next = element_match[e];
+                    if next == UNMATCHED {
+                        found_free_element = true;
+                    } else if dist[next] == UNMATCHED {
+                        dist[next] = dist[p] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            if !found_free_element {
+                return pattern_match;
+            }
+
+            // Depth-first search along the layers for vertex-disjoint augmenting paths
+            for p in 0..n_patterns {
+                if pattern_match[p] == UNMATCHED {
+                    augment(
+                        p,
+                        adjacency,
+                        &mut pattern_match,
+                        &mut element_match,
+                        &mut dist,
+                    );
                 }
-                matched[i] = false;
             }
         }
+    }
+
+    fn augment(
+        p: usize,
+        adjacency: &[Vec<usize>],
+        pattern_match: &mut [usize],
+        element_match: &mut [usize],
+        dist: &mut [usize],
+    ) -> bool {
+        for &e in &adjacency[p] {
+            let next = element_match[e];
+            let advances = next == UNMATCHED
+                || (dist[next] == dist[p] + 1
+                    && augment(next, adjacency, pattern_match, element_match, dist));
+            if advances {
+                pattern_match[p] = e;
+                element_match[e] = p;
+                return true;
+            }
+        }
+
+        // Dead end: drop this pattern from the current phase
+        dist[p] = UNMATCHED;
         false
     }
 }
@@ -0,0 +1,103 @@
+//! Renderers turning an [`ErrorReport`] into text.
+//!
+//! `Display` on a report, and so the `assert_struct!` panic message, uses the
+//! renderer named by the `ASSERT_STRUCT_FORMAT` environment variable:
+//!
+//! | Value    | Renderer   | Output                                           |
+//! |----------|------------|--------------------------------------------------|
+//! | unset    | [`Human`]  | Annotated source snippet                         |
+//! | `human`  | [`Human`]  | Annotated source snippet                         |
+//! | `json`   | [`Json`]   | JSON Lines, see [`ErrorReport::to_json`]         |
+//! | `github` | [`GitHub`] | GitHub Actions `::error` workflow commands       |
+//!
+//! Other renderers can implement [`Render`] on top of
+//! [`ErrorReport::failures`] and be used through [`ErrorReport::render`].
+
+use std::fmt;
+
+use crate::ErrorReport;
+
+/// Renders an [`ErrorReport`] with at least one failure.
+pub trait Render {
+    fn render(&self, report: &ErrorReport, out: &mut dyn fmt::Write) -> fmt::Result;
+}
+
+/// The annotated source snippet, falling back to a plain listing of the
+/// failures when the source file can't be read.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Human;
+
+impl Render for Human {
+    fn render(&self, report: &ErrorReport, out: &mut dyn fmt::Write) -> fmt::Result {
+        report.render_snippets(out)
+    }
+}
+
+/// JSON Lines, one object per failure. See [`ErrorReport::to_json`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json;
+
+impl Render for Json {
+    fn render(&self, report: &ErrorReport, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&report.to_json())
+    }
+}
+
+/// GitHub Actions workflow commands, one `::error` line per failure, which
+/// show up as annotations on the failing patterns in pull request diffs:
+///
+/// ```text
+/// ::error file=tests/users.rs,line=12,endLine=12,col=10,endColumn=13,title=assert_struct! failed::user.age: got 30
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GitHub;
+
+impl Render for GitHub {
+    fn render(&self, report: &ErrorReport, out: &mut dyn fmt::Write) -> fmt::Result {
+        let title = match report.message() {
+            Some(message) => format!("assert_struct! failed: {message}"),
+            None => "assert_struct! failed".to_string(),
+        };
+
+        for failure in report.failures() {
+            let location = failure.location();
+            writeln!(
+                out,
+                "::error file={},line={},endLine={},col={},endColumn={},title={}::{}",
+                escape_property(location.file()),
+                location.line(),
+                location.end_line(),
+                location.column(),
+                location.end_column(),
+                escape_property(&title),
+                escape_data(&failure.to_string()),
+            )?;
+            for nested in failure.nested() {
+                self.render(nested, out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The renderer selected by the `ASSERT_STRUCT_FORMAT` environment variable.
+/// Unknown values select [`Human`].
+pub(crate) fn from_env() -> &'static dyn Render {
+    match std::env::var("ASSERT_STRUCT_FORMAT").as_deref() {
+        Ok("json") => &Json,
+        Ok("github") => &GitHub,
+        _ => &Human,
+    }
+}
+
+/// Escape the message of a workflow command.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
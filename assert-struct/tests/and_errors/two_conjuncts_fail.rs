@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Order {
+    id: String,
+    total: u32,
+}
+
+pub fn test_case() {
+    let order = Order {
+        id: "ord-0".to_string(),
+        total: 0,
+    };
+
+    assert_struct!(order, Order {
+        id: != "ord-0" && != "tmp",
+        total: > 0 && < 1000,
+    });
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+struct Record {
+    id: String,
+    score: i32,
+    tags: Vec<String>,
+    parent: Option<u32>,
+}
+
+#[test]
+fn test_and_comparisons() {
+    let r = Record {
+        id: "id-42".to_string(),
+        score: 73,
+        tags: vec!["alpha".to_string(), "beta".to_string()],
+        parent: Some(7),
+    };
+    assert_struct!(r, Record {
+        score: > 0 && < 100 && != 50,
+        ..
+    });
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_and_regex_and_comparison() {
+    let r = Record {
+        id: "id-42".to_string(),
+        score: 73,
+        tags: vec!["alpha".to_string(), "beta".to_string()],
+        parent: Some(7),
+    };
+    assert_struct!(r, Record {
+        id: =~ r"^id-" && != "id-0",
+        ..
+    });
+}
+
+#[test]
+fn test_and_structural_and_operator() {
+    let r = Record {
+        id: "id-42".to_string(),
+        score: 73,
+        tags: vec!["alpha".to_string(), "beta".to_string()],
+        parent: Some(7),
+    };
+    assert_struct!(r, Record {
+        tags: ["alpha", ..] && #("beta", ..),
+        tags.len(): 1..=5 && !0,
+        parent: Some(> 0) && !Some(99),
+        ..
+    });
+}
+
+#[test]
+fn test_and_binds_tighter_than_or() {
+    let value = 150;
+    // `(> 0 && < 100) | >= 150`
+    assert_struct!(value, > 0 && < 100 | >= 150);
+}
+
+#[test]
+fn test_and_in_slice_and_enum_args() {
+    let values = vec![Some(3), None];
+    assert_struct!(values, [Some(>= 1 && <= 5), None]);
+}
+
+#[test]
+fn test_and_parenthesized_logical_and() {
+    let flag = true;
+    let a = true;
+    let b = true;
+    assert_struct!(flag, == (a && b));
+}
+
+#[test]
+fn test_and_leading_reference_operand() {
+    let value: &i32 = &5;
+    assert_struct!(value, == &5 && != &6);
+}
+
+#[test]
+fn test_and_reports_each_conjunct() {
+    let r = Record {
+        id: "id-42".to_string(),
+        score: 73,
+        tags: vec!["alpha".to_string(), "beta".to_string()],
+        parent: Some(7),
+    };
+    let report = check_struct!(r, Record {
+        score: < 50 && > 80 && != 73,
+        ..
+    })
+    .unwrap_err();
+
+    let patterns: Vec<_> = report.failures().map(|f| f.pattern()).collect();
+    assert_eq!(patterns, ["< 50", "> 80", "!= 73"]);
+}
+
+#[test]
+fn test_and_only_failing_conjunct_reported() {
+    let r = Record {
+        id: "id-42".to_string(),
+        score: 73,
+        tags: vec!["alpha".to_string(), "beta".to_string()],
+        parent: Some(7),
+    };
+    let report = check_struct!(r, Record {
+        score: > 0 && < 10,
+        ..
+    })
+    .unwrap_err();
+
+    assert_eq!(report.len(), 1);
+    assert_eq!(report.failures().next().unwrap().pattern(), "< 10");
+}
+
+error_message_test!("and_errors/two_conjuncts_fail.rs", two_conjuncts_fail);
@@ -0,0 +1,183 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+use std::collections::HashMap;
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+struct Metrics {
+    ratio: f64,
+    latency_ms: f32,
+    samples: Vec<f64>,
+    weights: HashMap<String, f64>,
+}
+
+#[test]
+fn test_approx_absolute_f64() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        ratio: ~ 0.3 +- 1e-9,
+        ..
+    });
+}
+
+#[test]
+fn test_approx_relative() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        ratio: ~ 0.3 +- 0.1%,
+        latency_ms: ~ 12.4 +- 1%,
+        ..
+    });
+}
+
+#[test]
+fn test_approx_f32() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        latency_ms: ~ 12.5 +- 0.001,
+        ..
+    });
+}
+
+#[test]
+fn test_approx_expressions() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    let target = 0.3;
+    let eps = f64::EPSILON * 4.0;
+    assert_struct!(m, Metrics {
+        ratio: ~ target +- eps,
+        latency_ms: ~ 25.0 / 2.0 +- 0,
+        ..
+    });
+}
+
+#[test]
+fn test_approx_negative_expected() {
+    let value = -0.5_f64;
+    assert_struct!(value, ~ -0.5 +- 1e-12);
+}
+
+#[test]
+fn test_approx_in_slice() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        samples: [~ 1.0 +- 1e-6, ~ 2.0 +- 1e-6, ~ 3.0 +- 1e-5],
+        ..
+    });
+}
+
+#[test]
+fn test_approx_in_set() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        samples: #(~ 3.0 +- 1e-5, ~ 1.0 +- 1e-9, ..),
+        ..
+    });
+}
+
+#[test]
+fn test_approx_in_map() {
+    let mut weights = HashMap::new();
+    weights.insert("a".to_string(), 0.25);
+    let m = Metrics {
+        ratio: 0.1 + 0.2,
+        latency_ms: 12.5,
+        samples: vec![1.0, 2.000_000_1, 2.999_999],
+        weights,
+    };
+    assert_struct!(m, Metrics {
+        weights: #{ "a": ~ 0.25 +- 1e-12 },
+        ..
+    });
+}
+
+#[test]
+fn test_approx_combined() {
+    let value = Some(0.5_f64);
+    assert_struct!(value, Some(~ 0.5 +- 1e-9 | ~ 1.0 +- 1e-9));
+    assert_struct!(value, Some(!~ 0.0 +- 0.1));
+}
+
+#[test]
+fn test_approx_nan_never_matches() {
+    let value = f64::NAN;
+    assert!(check_struct!(value, ~ 0.0 +- f64::INFINITY).is_err());
+}
+
+#[test]
+fn test_approx_failure_details() {
+    let value = 0.31_f64;
+    let report = check_struct!(value, ~ 0.3 +- 0.001).unwrap_err();
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.actual(), "0.31");
+    assert_eq!(failure.expected(), Some("0.3 ± 0.001"));
+    assert_eq!(failure.pattern(), "~ 0.3 +- 0.001");
+}
+
+#[test]
+fn test_approx_f32_compared_at_f32_precision() {
+    // 0.3f32 is about 1.19e-8 away from the f64 literal 0.3
+    let value = 0.3_f32;
+    assert_struct!(value, ~ 0.3 +- 1e-12);
+    assert_struct!(value, ~ 0.3 +- 0%);
+}
+
+#[test]
+fn test_approx_relative_to_zero() {
+    let value = 0.0_f64;
+    assert_struct!(value, ~ 0.0 +- 5%);
+
+    let value = 1e-300_f64;
+    let report = check_struct!(value, ~ 0.0 +- 5%).unwrap_err();
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.expected(), Some("0.0 ± 5.0%"));
+    assert!(!report.to_string().contains("inf"));
+}
+
+error_message_test!("approx_errors/absolute.rs", absolute);
+error_message_test!("approx_errors/relative.rs", relative);
+error_message_test!("approx_errors/relative_to_zero.rs", relative_to_zero);
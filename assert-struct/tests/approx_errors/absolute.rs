@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Stats {
+    mean: f64,
+    stddev: f64,
+}
+
+pub fn test_case() {
+    let stats = Stats {
+        mean: 10.25,
+        stddev: 1.5,
+    };
+
+    assert_struct!(stats, Stats {
+        mean: ~ 10.0 +- 0.1,
+        stddev: ~ 1.5 +- 0.01,
+    });
+}
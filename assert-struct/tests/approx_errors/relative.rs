@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let samples = vec![100.0_f32, 104.0];
+
+    assert_struct!(samples, [~ 100.0 +- 1%, ~ 100.0 +- 1%]);
+}
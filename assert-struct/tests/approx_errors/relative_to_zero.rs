@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let drift = 0.001_f64;
+
+    assert_struct!(drift, ~ 0.0 +- 5%);
+}
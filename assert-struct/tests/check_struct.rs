@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+use assert_struct::{ErrorReport, PatternKind, check_struct};
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_check_struct_ok() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let result = check_struct!(user, User {
+        name: "Alice",
+        age: >= 18,
+        ..
+    });
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_check_struct_err_collects_failures() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let line = line!();
+    let report = check_struct!(user, User {
+        name: "Bob",
+        age: < 18,
+        tags: ["admin"],
+    })
+    .unwrap_err();
+
+    assert_eq!(report.len(), 2);
+
+    let failures: Vec<_> = report.failures().collect();
+    assert_eq!(failures[0].actual(), "\"Alice\"");
+    assert_eq!(failures[0].pattern(), "\"Bob\"");
+    assert_eq!(failures[1].actual(), "30");
+    assert_eq!(failures[1].pattern(), "< 18");
+    assert_eq!(failures[1].expected(), None);
+    let location = failures[1].location();
+    assert_eq!(location.file(), "assert-struct/tests/check_struct.rs");
+    assert_eq!(location.line(), line + 3);
+}
+
+#[test]
+fn test_check_struct_expected_value() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let report = check_struct!(user, User { age: == 31, .. }).unwrap_err();
+
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.expected(), Some("31"));
+    assert_eq!(failure.to_string(), "user.age: expected 31, got 30");
+}
+
+#[test]
+fn test_check_struct_question_mark() -> Result<(), ErrorReport> {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    check_struct!(user, User { age: 30, .. })?;
+    check_struct!(user.tags, [== "admin"])?;
+    Ok(())
+}
+
+#[test]
+fn test_check_struct_does_not_consume() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let _ = check_struct!(user, User { name: "Alice", .. });
+    assert_eq!(user.age, 30);
+}
+
+#[path = "check_struct_errors/display_matches_panic.rs"]
+mod display_matches_panic;
+
+#[test]
+fn test_check_struct_display_matches_panic() {
+    let _guard = assert_struct::__macro_support::PlainOutputGuard::new();
+    insta::assert_snapshot!(display_matches_panic::report().to_string());
+}
+
+#[test]
+fn test_failure_kind_and_location() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let line = line!();
+    let report = check_struct!(user, User {
+        name: "Bob",
+        age: < 18,
+        tags: [],
+    })
+    .unwrap_err();
+
+    let kinds: Vec<_> = report.failures().map(|f| f.kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            PatternKind::Simple,
+            PatternKind::Comparison,
+            PatternKind::Slice
+        ]
+    );
+
+    let location = report.failures().nth(1).unwrap().location();
+    assert_eq!(location.file(), "assert-struct/tests/check_struct.rs");
+    assert_eq!((location.line(), location.column()), (line + 3, 14));
+    assert_eq!((location.end_line(), location.end_column()), (line + 3, 17));
+    assert_eq!(
+        location.to_string(),
+        format!("assert-struct/tests/check_struct.rs:{}:14", line + 3)
+    );
+}
+
+#[test]
+fn test_failures_iterator() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string()],
+    };
+    let line = line!();
+    let report = check_struct!(
+        user,
+        User {
+            name: "Bob",
+            age: 0,
+            ..
+        }
+    )
+    .unwrap_err();
+
+    assert_eq!(report.failures().len(), 2);
+    let paths: Vec<_> = (&report).into_iter().rev().map(|f| f.path()).collect();
+    assert_eq!(paths, ["user.age", "user.name"]);
+    assert_eq!(
+        format!("{:?}", report.failures().next().unwrap()),
+        format!(
+            "Failure {{ path: \"user.name\", kind: Simple, pattern: \"\\\"Bob\\\"\", \
+             location: Location {{ file: \"assert-struct/tests/check_struct.rs\", line: {0}, \
+             column: 19, end_line: {0}, end_column: 23 }}, actual: \"\\\"Alice\\\"\", \
+             expected: None }}",
+            line + 4
+        )
+    );
+}
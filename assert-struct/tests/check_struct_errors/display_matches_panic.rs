@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use assert_struct::{ErrorReport, check_struct};
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+pub fn report() -> ErrorReport {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+    };
+    check_struct!(user, User { age: > 40, .. }).unwrap_err()
+}
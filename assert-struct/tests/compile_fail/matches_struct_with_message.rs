@@ -0,0 +1,6 @@
+use assert_struct::matches_struct;
+
+fn main() {
+    let value = Some(5);
+    let _ = matches_struct!(value, Some(> 0), "value {} is not positive", 5);
+}
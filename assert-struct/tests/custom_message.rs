@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+use std::cell::Cell;
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn test_message_passing_assertion() {
+    let point = Point { x: 1, y: 2 };
+    assert_struct!(point, Point { x: 1, y: 2 }, "point {:?} is wrong", point);
+}
+
+#[test]
+fn test_message_trailing_comma() {
+    let point = Point { x: 1, y: 2 };
+    assert_struct!(point, Point { x: 1, y: 2 },);
+    assert_struct!(point, Point { x: 1, y: 2 }, "message",);
+}
+
+#[test]
+fn test_message_args_evaluated_only_on_failure() {
+    let evaluated = Cell::new(false);
+    let describe = || {
+        evaluated.set(true);
+        "origin"
+    };
+
+    let point = Point { x: 0, y: 0 };
+    assert_struct!(point, Point { x: 0, y: 0 }, "{} is wrong", describe());
+    assert!(!evaluated.get());
+}
+
+#[test]
+fn test_check_struct_message() {
+    let point = Point { x: 1, y: 2 };
+    let report = check_struct!(point, Point { x: > 5, .. }, "case {}", 3).unwrap_err();
+    assert_eq!(report.message(), Some("case 3"));
+
+    let report = check_struct!(point, Point { x: > 5, .. }).unwrap_err();
+    assert_eq!(report.message(), None);
+}
+
+error_message_test!("custom_message_errors/loop_case.rs", loop_case);
+error_message_test!("custom_message_errors/inline_args.rs", inline_args);
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let user_id = 7;
+    let scores = vec![10, 20];
+    assert_struct!(scores, [> 10, > 10], "scores for user {user_id}");
+}
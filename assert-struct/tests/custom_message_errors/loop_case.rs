@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+pub fn test_case() {
+    let responses = [
+        Response {
+            status: 200,
+            body: "ok".to_string(),
+        },
+        Response {
+            status: 503,
+            body: "unavailable".to_string(),
+        },
+    ];
+
+    for (id, response) in responses.iter().enumerate() {
+        assert_struct!(response, Response { status: 200, .. }, "request {} failed", id);
+    }
+}
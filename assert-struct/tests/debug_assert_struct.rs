@@ -0,0 +1,66 @@
+#![allow(dead_code)]
+use assert_struct::debug_assert_struct;
+
+#[derive(Debug)]
+struct Counter {
+    value: u32,
+    label: String,
+}
+
+#[test]
+fn test_debug_assert_struct_passes() {
+    let counter = Counter {
+        value: 3,
+        label: "hits".to_string(),
+    };
+    debug_assert_struct!(
+        counter,
+        Counter {
+            value: 1..=5,
+            label: "hits",
+        }
+    );
+}
+
+#[test]
+fn test_debug_assert_struct_with_message() {
+    let counter = Counter {
+        value: 3,
+        label: "hits".to_string(),
+    };
+    debug_assert_struct!(counter, Counter { value: > 0, .. }, "counter {} invalid", counter.label);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "assert_struct! failed")]
+fn test_debug_assert_struct_panics_in_debug() {
+    let counter = Counter {
+        value: 0,
+        label: "hits".to_string(),
+    };
+    debug_assert_struct!(counter, Counter { value: > 0, .. });
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+#[deny(warnings)]
+fn test_debug_assert_struct_skipped_in_release() {
+    let evaluated = std::cell::Cell::new(false);
+    let counter = || {
+        evaluated.set(true);
+        Counter {
+            value: 0,
+            label: "hits".to_string(),
+        }
+    };
+    debug_assert_struct!(counter(), Counter { value: > 0, .. });
+    assert!(!evaluated.get());
+
+    // Only used by the assertion, which must not warn about it being unused
+    let unused_in_release = Counter {
+        value: 0,
+        label: "hits".to_string(),
+    };
+    debug_assert_struct!(unused_in_release, Counter { value: > 0, .. });
+}
@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+use std::cell::Cell;
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+struct Reading {
+    celsius: i32,
+}
+
+/// Comparable with `Reading`, but without a `Debug` impl.
+struct Threshold(i32);
+
+impl PartialEq<Threshold> for Reading {
+    fn eq(&self, other: &Threshold) -> bool {
+        self.celsius == other.0
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    reading: Reading,
+}
+
+#[test]
+fn test_equality_with_non_debug_expected() {
+    let sensor = Sensor {
+        reading: Reading { celsius: 21 },
+    };
+    assert_struct!(sensor, Sensor {
+        reading: == Threshold(21),
+    });
+}
+
+#[test]
+#[should_panic(expected = "expected Threshold(30), got Reading { celsius: 21 }")]
+fn test_equality_with_non_debug_expected_fails_without_diff() {
+    let sensor = Sensor {
+        reading: Reading { celsius: 21 },
+    };
+    assert_struct!(sensor, Sensor {
+        reading: == Threshold(30),
+    });
+}
+
+#[test]
+fn test_equality_evaluates_expected_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        21
+    };
+    let sensor = Sensor {
+        reading: Reading { celsius: 21 },
+    };
+    assert_struct!(sensor, Sensor {
+        reading.celsius: == next(),
+    });
+    assert_eq!(calls, 1);
+}
+
+/// Counts how often its `reading()` method is called.
+#[derive(Debug)]
+struct Gauge {
+    calls: Cell<u32>,
+}
+
+impl Gauge {
+    fn reading(&self) -> Reading {
+        self.calls.set(self.calls.get() + 1);
+        Reading { celsius: 21 }
+    }
+}
+
+#[derive(Debug)]
+struct Station {
+    gauge: Gauge,
+}
+
+#[test]
+fn test_failed_equality_evaluates_actual_once() {
+    let station = Station {
+        gauge: Gauge {
+            calls: Cell::new(0),
+        },
+    };
+    let report = check_struct!(station, Station {
+        gauge.reading(): == Threshold(30),
+    });
+    assert!(report.is_err());
+    assert_eq!(station.gauge.calls.get(), 1);
+}
+
+error_message_test!("diffs_errors/nested_field_differs.rs", nested_field_differs);
+error_message_test!("diffs_errors/constant_differs.rs", constant_differs);
+error_message_test!("diffs_errors/small_struct_differs.rs", small_struct_differs);
+error_message_test!("diffs_errors/long_string_literal.rs", long_string_literal);
+error_message_test!(
+    "diffs_errors/multiline_string_equality.rs",
+    multiline_string_equality
+);
+error_message_test!(
+    "diffs_errors/multiline_string_literal.rs",
+    multiline_string_literal
+);
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Limits {
+    max_connections: u32,
+    max_body_bytes: u64,
+    allowed_methods: &'static [&'static str],
+}
+
+const DEFAULT_LIMITS: Limits = Limits {
+    max_connections: 100,
+    max_body_bytes: 1024,
+    allowed_methods: &["GET", "POST"],
+};
+
+pub fn test_case() {
+    let limits = Limits {
+        max_connections: 100,
+        max_body_bytes: 1024,
+        allowed_methods: &["GET", "PUT"],
+    };
+    assert_struct!(limits, DEFAULT_LIMITS);
+}
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Query {
+    sql: String,
+}
+
+pub fn test_case() {
+    let query = Query {
+        sql: "SELECT id, email FROM users WHERE active = 1 ORDER BY id".to_string(),
+    };
+    assert_struct!(query, Query {
+        sql: "SELECT id, name FROM users WHERE active = 1 ORDER BY id",
+    });
+}
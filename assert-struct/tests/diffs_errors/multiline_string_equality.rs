@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Page {
+    html: String,
+}
+
+pub fn test_case() {
+    let page = Page {
+        html: "<html>\n  <head>\n    <title>Home</title>\n  </head>\n  <body>\n    <h1>Welcome, guest</h1>\n    <p>Latest news</p>\n  </body>\n</html>".to_string(),
+    };
+    let expected = "<html>\n  <head>\n    <title>Home</title>\n  </head>\n  <body>\n    <h1>Welcome, Alice</h1>\n    <p>Latest news</p>\n  </body>\n</html>";
+    assert_struct!(page, Page {
+        html: == expected,
+    });
+}
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Log {
+    output: String,
+}
+
+pub fn test_case() {
+    let log = Log {
+        output: "starting\nloading config\nlistening on :8080\nready".to_string(),
+    };
+    assert_struct!(log, Log {
+        output: "starting\nloading config\nlistening on :9090\nready\n",
+    });
+}
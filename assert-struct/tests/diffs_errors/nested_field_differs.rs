@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+    workers: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    name: String,
+    server: Server,
+    features: Vec<String>,
+}
+
+fn config(port: u16) -> Config {
+    Config {
+        name: "api".to_string(),
+        server: Server {
+            host: "localhost".to_string(),
+            port,
+            workers: 4,
+        },
+        features: vec!["auth".to_string(), "metrics".to_string()],
+    }
+}
+
+#[derive(Debug)]
+struct Deployment {
+    config: Config,
+}
+
+pub fn test_case() {
+    let deployment = Deployment { config: config(9090) };
+    let expected = config(8080);
+    assert_struct!(deployment, Deployment {
+        config: == expected,
+    });
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug)]
+struct Shape {
+    origin: Point,
+}
+
+pub fn test_case() {
+    let shape = Shape {
+        origin: Point { x: 1, y: 2 },
+    };
+    assert_struct!(shape, Shape {
+        origin: == Point { x: 1, y: 3 },
+    });
+}
@@ -0,0 +1,85 @@
+#![allow(dead_code)]
+use assert_struct::__macro_support::{
+    ComparisonOp, EmbeddedSource, ErrorReport, IgnoreSourceFilesGuard, NodeKind, PathStep,
+    PatternNode, PlainOutputGuard,
+};
+use assert_struct::check_struct;
+
+// The node a `check_struct!(user, User { age: >= 18, .. })` on lines 40-43
+// of a file that no longer exists would generate for `>= 18`.
+static AGE: PatternNode = PatternNode {
+    kind: NodeKind::Comparison {
+        op: ComparisonOp::GreaterEqual,
+        value: "18",
+    },
+    parent: None,
+    step: PathStep::Root("user.age"),
+    line_start: 42,
+    col_start: 13,
+    line_end: 42,
+    col_end: 18,
+};
+
+static SOURCE: EmbeddedSource = EmbeddedSource {
+    first_line: 40,
+    text: "user, User {
+        name: \"Alice\",
+        age: >= 18,
+        ..
+    }",
+};
+
+fn report(source: Option<&'static EmbeddedSource>) -> ErrorReport {
+    let mut report = ErrorReport::new("/nonexistent/my-crate", "my-crate/tests/gone.rs");
+    if let Some(source) = source {
+        report = report.with_source(source);
+    }
+    report.push(&AGE, "16".to_string(), None);
+    report
+}
+
+#[test]
+fn test_snippet_from_embedded_source() {
+    let _guard = assert_struct::__macro_support::PlainOutputGuard::new();
+    insta::assert_snapshot!(report(Some(&SOURCE)).to_string());
+}
+
+#[test]
+fn test_plain_listing_without_source() {
+    let _guard = assert_struct::__macro_support::PlainOutputGuard::new();
+    insta::assert_snapshot!(report(None).to_string());
+}
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+    email: String,
+}
+
+#[test]
+fn test_macro_embeds_invocation_source() {
+    let _guard = PlainOutputGuard::new();
+    let user = User {
+        name: "Alice".to_string(),
+        age: 16,
+        email: "alice@example.com".to_string(),
+    };
+    let report = check_struct!(user, User {
+        name: "Alice",
+        age: >= 18,  // Comments aren't embedded
+        email: "alice@example.org",
+    })
+    .unwrap_err();
+
+    let from_file = report.to_string();
+    let embedded = {
+        let _guard = IgnoreSourceFilesGuard::new();
+        report.to_string()
+    };
+    assert_eq!(
+        embedded,
+        from_file.replace("  // Comments aren't embedded", "")
+    );
+    insta::assert_snapshot!(embedded);
+}
@@ -372,6 +372,23 @@ fn test_nested_enum_struct_variant() {
     );
 }
 
+/// A generic enum whose all-caps unit variant reads like a constant.
+#[derive(Debug, PartialEq)]
+enum Tree<T> {
+    E,
+    Node(T),
+}
+
+#[test]
+fn test_generic_all_caps_unit_variant() {
+    let tree = Tree::<i32>::E;
+    assert_struct!(tree, Tree::E);
+
+    // Tuple elements are matched through references
+    let trees = (Tree::Node(1), Tree::<i32>::E);
+    assert_struct!(trees, (Tree::Node(1), Tree::E));
+}
+
 // Failure tests
 error_message_test!(
     "enums_errors/result_expected_ok_got_err.rs",
@@ -390,6 +407,11 @@ error_message_test!(
 
 error_message_test!("enums_errors/enum_variant.rs", enum_variant);
 
+error_message_test!(
+    "enums_errors/generic_all_caps_unit_variant.rs",
+    generic_all_caps_unit_variant
+);
+
 // Simple value display tests for error messages
 #[derive(Debug)]
 struct SimpleDisplayFoo {
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+enum Tree<T> {
+    E,
+    Node(T),
+}
+
+pub fn test_case() {
+    let tree = Tree::Node(1);
+
+    assert_struct!(tree, Tree::E);
+}
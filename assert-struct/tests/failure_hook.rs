@@ -0,0 +1,67 @@
+//! The failure hook is process-wide, so everything that depends on it runs in
+//! a single test.
+use assert_struct::{ErrorReport, assert_struct, debug_assert_struct};
+use std::panic;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+struct Job {
+    name: &'static str,
+    attempts: u32,
+}
+
+// A custom payload a harness could downcast
+#[derive(Debug)]
+struct HarnessFailure {
+    paths: Vec<String>,
+}
+
+static RECORDED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn failing_assertion() {
+    let job = Job {
+        name: "deploy",
+        attempts: 3,
+    };
+    assert_struct!(job, Job {
+        name: "build",
+        attempts: < 3,
+    });
+}
+
+#[test]
+fn test_failure_hook() {
+    // Recording hook: the failure is soft and execution continues
+    assert_struct::set_failure_hook(|report: ErrorReport| {
+        let mut recorded = RECORDED.lock().unwrap();
+        recorded.extend(report.failures().map(|f| f.to_string()));
+    });
+    failing_assertion();
+    // Skipped in release builds
+    debug_assert_struct!(1, 2);
+    let mut expected = vec!["job.name: got \"deploy\"", "job.attempts: got 3"];
+    if cfg!(debug_assertions) {
+        expected.push("1: got 1");
+    }
+    assert_eq!(*RECORDED.lock().unwrap(), expected);
+
+    // Passing assertions never reach the hook
+    assert_struct!(1, 1);
+    assert_eq!(RECORDED.lock().unwrap().len(), expected.len());
+
+    // Panicking hook with a custom payload
+    assert_struct::set_failure_hook(|report: ErrorReport| {
+        let paths = report.failures().map(|f| f.path()).collect();
+        panic::panic_any(HarnessFailure { paths });
+    });
+    let payload = panic::catch_unwind(failing_assertion).unwrap_err();
+    let failure = payload.downcast_ref::<HarnessFailure>().unwrap();
+    assert_eq!(failure.paths, ["job.name", "job.attempts"]);
+
+    // Taking the hook restores the default panic
+    assert!(assert_struct::take_failure_hook().is_some());
+    assert!(assert_struct::take_failure_hook().is_none());
+    let payload = panic::catch_unwind(failing_assertion).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("job.attempts: got 3"));
+}
@@ -127,3 +127,7 @@ error_message_test!(
     "field_operations_errors/slice_with_deref_mismatch.rs",
     slice_with_deref_mismatch
 );
+error_message_test!(
+    "field_operations_errors/method_call_path.rs",
+    method_call_path
+);
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Response {
+    body: Body,
+}
+
+#[derive(Debug)]
+struct Body {
+    items: Vec<String>,
+    owner: Option<String>,
+}
+
+pub fn test_case() {
+    let response = Response {
+        body: Body {
+            items: vec!["a".to_string(), "b".to_string()],
+            owner: None,
+        },
+    };
+
+    assert_struct!(response.body, Body {
+        items.len(): 3,
+        owner.is_some(): true,
+    });
+}
@@ -0,0 +1,45 @@
+//! Renderer selection with `ASSERT_STRUCT_FORMAT`. Setting the variable races
+//! with any other thread reading the environment, so this binary holds a
+//! single test.
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+use std::panic;
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+#[test]
+fn test_format_selected_by_env_var() {
+    let user = User {
+        name: "Alice".to_string(),
+        age: 30,
+    };
+    let report = check_struct!(user, User { age: < 18, .. }).unwrap_err();
+
+    // SAFETY: this is the only test in this binary, so no other thread reads
+    // the environment while it is changed.
+    unsafe { std::env::set_var("ASSERT_STRUCT_FORMAT", "json") };
+    let json = report.to_string();
+    unsafe { std::env::remove_var("ASSERT_STRUCT_FORMAT") };
+    assert_eq!(json, report.to_json());
+
+    // The panic message of a failed assertion is rendered the same way
+    unsafe { std::env::set_var("ASSERT_STRUCT_FORMAT", "github") };
+    let github = panic::catch_unwind(|| {
+        let user = User {
+            name: "Alice".to_string(),
+            age: 30,
+        };
+        assert_struct!(user, User { age: < 18, .. });
+    });
+    unsafe { std::env::remove_var("ASSERT_STRUCT_FORMAT") };
+    let payload = github.unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("::error file=assert-struct/tests/format_env.rs,"));
+    assert!(message.ends_with("::user.age: got 30\n"));
+
+    assert!(report.to_string().contains("assert_struct! failed"));
+}
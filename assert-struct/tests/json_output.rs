@@ -0,0 +1,46 @@
+use assert_struct::check_struct;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+    settings: HashMap<String, String>,
+}
+
+#[test]
+fn test_json_one_object_per_failure() {
+    let mut settings = HashMap::new();
+    settings.insert("theme".to_string(), "dark".to_string());
+    let user = User {
+        name: "Alice \"Al\"".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string(), "ops\tteam".to_string()],
+        settings,
+    };
+    let report = check_struct!(user, User {
+        name: "Alice",
+        age: == 31,
+        tags: ["admin", "ops"],
+        settings: #{ "theme": "light" },
+    })
+    .unwrap_err();
+
+    insta::assert_snapshot!(report.to_json());
+}
+
+#[test]
+fn test_json_includes_custom_message() {
+    let mut settings = HashMap::new();
+    settings.insert("theme".to_string(), "dark".to_string());
+    let user = User {
+        name: "Alice \"Al\"".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string(), "ops\tteam".to_string()],
+        settings,
+    };
+    let report = check_struct!(user, User { age: < 18, .. }, "user {}", 7).unwrap_err();
+
+    insta::assert_snapshot!(report.to_json());
+}
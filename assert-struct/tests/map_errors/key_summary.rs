@@ -0,0 +1,21 @@
+use assert_struct::assert_struct;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Config {
+    settings: HashMap<String, String>,
+}
+
+pub fn test_case() {
+    let mut settings = HashMap::new();
+    settings.insert("host".to_string(), "localhost".to_string());
+    settings.insert("port".to_string(), "8080".to_string());
+    settings.insert("user".to_string(), "admin".to_string());
+    settings.insert("debug".to_string(), "true".to_string());
+
+    let config = Config { settings };
+
+    assert_struct!(config, Config {
+        settings: #{ "host": "localhost", "port": "443", "timeout": "30" },
+    });
+}
@@ -1,4 +1,4 @@
-use assert_struct::assert_struct;
+use assert_struct::{assert_struct, check_struct};
 use std::collections::{BTreeMap, HashMap};
 
 #[path = "util/mod.rs"]
@@ -200,6 +200,47 @@ fn test_map_with_regex_patterns() {
 error_message_test!("map_errors/exact_length_mismatch.rs", exact_length_mismatch);
 error_message_test!("map_errors/missing_key.rs", missing_key);
 error_message_test!("map_errors/value_mismatch.rs", value_mismatch);
+error_message_test!("map_errors/key_summary.rs", key_summary);
+
+#[test]
+fn test_exact_map_repeated_key() {
+    #[derive(Debug)]
+    struct Counts {
+        map: HashMap<String, i32>,
+    }
+
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), 1);
+    map.insert("b".to_string(), 2);
+    let mut counts = Counts { map };
+
+    // Both patterns apply to "a", but "b" is still unexpected
+    let report = check_struct!(counts, Counts {
+        map: #{ "a": > 0, "a": < 5 },
+    })
+    .unwrap_err();
+    assert_eq!(report.len(), 1);
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.to_string(), "counts.map: 1 unexpected key");
+
+    counts.map.remove("b");
+    assert_struct!(counts, Counts {
+        map: #{ "a": > 0, "a": < 5 },
+    });
+}
+
+#[test]
+fn test_exact_map_expression_keys() {
+    let mut map = HashMap::new();
+    map.insert(1, "one");
+    map.insert(2, "two");
+
+    let one = 1;
+    let also_one = 1;
+    let report = check_struct!(map, #{ one: "one", also_one: "one" }).unwrap_err();
+    assert_eq!(report.len(), 1);
+    assert_struct!(map, #{ one: "one", one + 1: "two" });
+}
 
 #[test]
 fn test_empty_map() {
@@ -264,6 +305,10 @@ impl<K, V> CustomMap<K, V> {
         self.entries.len()
     }
 
+    fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
     fn get<Q>(&self, key: &Q) -> Option<&V>
     where
         K: std::borrow::Borrow<Q>,
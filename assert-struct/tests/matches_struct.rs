@@ -0,0 +1,195 @@
+#![allow(dead_code)]
+use assert_struct::{matches_struct, pattern};
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    price: u32,
+    tags: Vec<String>,
+}
+
+// ── matches_struct! ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_matches_struct_true() {
+    let item = Item {
+        name: "apple".to_string(),
+        price: 3,
+        tags: vec!["fruit".to_string()],
+    };
+    assert!(matches_struct!(item, Item {
+        name: "apple",
+        price: > 0,
+        ..
+    }));
+}
+
+#[test]
+fn test_matches_struct_false() {
+    let item = Item {
+        name: "sample".to_string(),
+        price: 0,
+        tags: vec![],
+    };
+    assert!(!matches_struct!(item, Item { price: > 0, .. }));
+}
+
+#[test]
+fn test_matches_struct_non_struct_root() {
+    let value = Some(42);
+    assert!(matches_struct!(value, Some(40..50)));
+    assert!(!matches_struct!(value, None));
+    assert!(matches_struct!(vec![1, 2, 3], [1, .., 3]));
+}
+
+#[test]
+fn test_matches_struct_in_condition() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    let count = items
+        .iter()
+        .filter(|item| matches_struct!(item, { tags: #("fruit", ..) }))
+        .count();
+    assert_eq!(count, 2);
+}
+
+// ── pattern! ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_pattern_any() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    assert!(items.iter().any(pattern!(Item { price: 0, .. })));
+    assert!(!items.iter().any(pattern!(Item { price: > 100, .. })));
+}
+
+#[test]
+fn test_pattern_position() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    assert_eq!(items.iter().position(pattern!({ name: "pear" })), Some(2));
+    assert_eq!(items.iter().position(pattern!({ name: "plum" })), None);
+}
+
+#[test]
+fn test_pattern_filter() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    let names: Vec<_> = items
+        .iter()
+        .filter(pattern!({ price: > 0, tags.len(): >= 1 }))
+        .map(|item| item.name.as_str())
+        .collect();
+    assert_eq!(names, ["apple", "pear"]);
+}
+
+#[test]
+fn test_pattern_all() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    assert!(items.iter().all(pattern!({ price: < 10 })));
+}
+
+#[test]
+fn test_pattern_captures_locals() {
+    let items = [
+        Item {
+            name: "apple".to_string(),
+            price: 3,
+            tags: vec!["fruit".to_string()],
+        },
+        Item {
+            name: "sample".to_string(),
+            price: 0,
+            tags: vec![],
+        },
+        Item {
+            name: "pear".to_string(),
+            price: 5,
+            tags: vec!["fruit".to_string(), "green".to_string()],
+        },
+    ];
+    let min_price = 4;
+    assert_eq!(
+        items
+            .iter()
+            .filter(pattern!({ price: >= min_price }))
+            .count(),
+        1
+    );
+}
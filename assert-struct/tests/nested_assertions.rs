@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct, matches_struct, soft};
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+pub struct Address {
+    pub city: &'static str,
+    pub zip: &'static str,
+}
+
+impl Address {
+    /// Checks the address with an assertion of its own.
+    pub fn validate(&self) -> bool {
+        assert_struct!(self, Address { zip.len(): 5, .. });
+        true
+    }
+}
+
+#[derive(Debug)]
+pub struct Customer {
+    pub name: &'static str,
+    pub address: Address,
+}
+
+pub fn customer() -> Customer {
+    Customer {
+        name: "Ada",
+        address: Address {
+            city: "Berlin",
+            zip: "1011",
+        },
+    }
+}
+
+error_message_test!(
+    "nested_assertions_errors/assertion_in_closure.rs",
+    assertion_in_closure
+);
+error_message_test!(
+    "nested_assertions_errors/assertion_in_method_call.rs",
+    assertion_in_method_call
+);
+
+#[test]
+fn test_nested_failures_are_children_of_outer_failure() {
+    let customer = customer();
+    let report = check_struct!(
+        customer,
+        Customer {
+            name: "Ada",
+            address: |a: &Address| a.validate(),
+        }
+    )
+    .unwrap_err();
+
+    let failure = report.failures().next().unwrap();
+    assert_eq!(
+        failure.to_string(),
+        "customer.address: nested assertion failed"
+    );
+    let nested = failure.nested();
+    assert_eq!(nested.len(), 1);
+    let inner = nested[0].failures().next().unwrap();
+    assert_eq!(inner.to_string(), "self.zip.len(): got 4");
+    assert_eq!(
+        inner.location().file(),
+        "assert-struct/tests/nested_assertions.rs"
+    );
+}
+
+#[test]
+fn test_passing_nested_assertion() {
+    let customer = Customer {
+        name: "Ada",
+        address: Address {
+            city: "Berlin",
+            zip: "10115",
+        },
+    };
+    assert_struct!(
+        customer,
+        Customer {
+            address: |a: &Address| a.validate(),
+            ..
+        }
+    );
+}
+
+#[test]
+fn test_nested_failure_fails_predicate() {
+    let customer = customer();
+    assert!(!matches_struct!(
+        customer,
+        Customer {
+            address: |a: &Address| a.validate(),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_nested_failures_in_json() {
+    let customer = customer();
+    let report = check_struct!(
+        customer,
+        Customer {
+            address: |a: &Address| a.validate(),
+            ..
+        }
+    )
+    .unwrap_err();
+
+    let lines: Vec<_> = report.to_json().lines().map(String::from).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("\"path\":\"customer.address\""));
+    assert!(lines[1].contains("\"path\":\"self.zip.len()\""));
+}
+
+#[test]
+fn test_soft_inside_closure_reports_to_outer() {
+    let customer = customer();
+    let report = check_struct!(
+        customer,
+        Customer {
+            address: |a: &Address| soft(|| {
+                assert_struct!(a.city, "Paris");
+                assert_struct!(a.zip, "75001");
+                true
+            }),
+            ..
+        }
+    )
+    .unwrap_err();
+
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.nested().len(), 2);
+}
+
+#[test]
+fn test_closure_keeps_running_after_nested_failure() {
+    // The inner assertion returns instead of panicking, so the rest of the
+    // closure still runs; the pattern fails even though the closure returns true
+    let ran_after = std::cell::Cell::new(false);
+    let customer = customer();
+    let report = check_struct!(
+        customer,
+        Customer {
+            address: |a: &Address| {
+                assert_struct!(a.city, "Paris");
+                ran_after.set(true);
+                true
+            },
+            ..
+        }
+    )
+    .unwrap_err();
+
+    assert!(ran_after.get());
+    assert_eq!(report.len(), 1);
+    assert_eq!(report.failures().next().unwrap().nested().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "self.zip.len(): got 4")]
+fn test_method_call_assertion_panics_on_its_own() {
+    // Only closure patterns collect inner failures; a method called in a field
+    // path panics as it would anywhere else
+    let customer = customer();
+    let _ = check_struct!(customer, Customer {
+        address.validate(): true,
+        ..
+    });
+}
@@ -0,0 +1,13 @@
+use super::{Address, Customer, customer};
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let customer = customer();
+    assert_struct!(customer, Customer {
+        name: "Ada",
+        address: |address: &Address| {
+            assert_struct!(address, Address { city: "Paris", .. });
+            true
+        },
+    });
+}
@@ -0,0 +1,10 @@
+use super::{Customer, customer};
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let customer = customer();
+    assert_struct!(customer, Customer {
+        name: "Grace",
+        address.validate(): true,
+    });
+}
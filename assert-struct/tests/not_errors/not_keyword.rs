@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let tags = vec!["beta", "internal"];
+
+    assert_struct!(tags, not(["beta", ..]));
+}
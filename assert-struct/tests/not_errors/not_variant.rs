@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+enum Status {
+    Active,
+    Closed,
+}
+
+#[derive(Debug)]
+struct Account {
+    status: Status,
+    balance: i64,
+}
+
+pub fn test_case() {
+    let account = Account {
+        status: Status::Closed,
+        balance: 0,
+    };
+
+    assert_struct!(account, Account {
+        status: !Status::Closed,
+        balance: !(0 | 1),
+    });
+}
@@ -0,0 +1,250 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct, matches_struct};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+enum Status {
+    Active,
+    Suspended { reason: String },
+    Closed,
+}
+
+#[derive(Debug)]
+struct Account {
+    id: String,
+    status: Status,
+    balance: i64,
+    roles: Vec<String>,
+    limits: HashMap<String, u32>,
+}
+
+#[test]
+fn test_not_enum_variant() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(
+        acct,
+        Account {
+            status: !Status::Closed,
+            ..
+        }
+    );
+}
+
+#[test]
+fn test_not_struct_variant() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(
+        acct,
+        Account {
+            status: !Status::Suspended { .. },
+            ..
+        }
+    );
+}
+
+#[test]
+fn test_not_keyword_form() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(acct, Account {
+        balance: not(< 0),
+        status: not(Status::Suspended { .. } | Status::Closed),
+        ..
+    });
+}
+
+#[test]
+fn test_not_parenthesized_alternation() {
+    let value = Some(3);
+    assert_struct!(value, Some(!(1 | 2)));
+}
+
+#[test]
+fn test_not_binds_tighter_than_or() {
+    let value = 2;
+    // `!1 | 2` is `(!1) | 2`
+    assert_struct!(value, !1 | 2);
+    assert_struct!(value, !(1 | 3));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_not_regex() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(acct, Account {
+        id: !=~ r"^tmp-",
+        ..
+    });
+}
+
+#[test]
+fn test_not_set() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(acct, Account {
+        roles: !#("admin", ..),
+        ..
+    });
+}
+
+#[test]
+fn test_not_map() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(acct, Account {
+        limits: !#{ "daily": > 1000, .. },
+        ..
+    });
+}
+
+#[test]
+fn test_not_inside_slice() {
+    let values = vec![1, 2, 3];
+    assert_struct!(values, [!0, !0, !(0 | 4)]);
+}
+
+#[test]
+fn test_not_does_not_affect_not_equal() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(acct, Account {
+        balance: != 0,
+        ..
+    });
+}
+
+#[test]
+fn test_double_negation() {
+    let value = Some(5);
+    assert_struct!(value, !!Some(5));
+}
+
+#[test]
+fn test_not_matches_struct() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert!(!matches_struct!(
+        acct,
+        Account {
+            status: !Status::Active,
+            ..
+        }
+    ));
+}
+
+#[test]
+#[should_panic(expected = "expected not to match")]
+fn test_not_failure_panics() {
+    let mut limits = HashMap::new();
+    limits.insert("daily".to_string(), 500);
+    let acct = Account {
+        id: "acct-17".to_string(),
+        status: Status::Active,
+        balance: 120,
+        roles: vec!["member".to_string(), "billing".to_string()],
+        limits,
+    };
+    assert_struct!(
+        acct,
+        Account {
+            status: !Status::Active,
+            ..
+        }
+    );
+}
+
+/// Counts how often its `next()` method is called.
+#[derive(Debug)]
+struct Sequence {
+    calls: Cell<u32>,
+}
+
+impl Sequence {
+    fn next(&self) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        0
+    }
+}
+
+#[derive(Debug)]
+struct Ticket {
+    seq: Sequence,
+}
+
+#[test]
+fn test_failed_not_evaluates_value_once() {
+    let ticket = Ticket {
+        seq: Sequence {
+            calls: Cell::new(0),
+        },
+    };
+    let report = check_struct!(ticket, Ticket { seq.next(): !0 });
+    assert!(report.is_err());
+    assert_eq!(ticket.seq.calls.get(), 1);
+}
+
+error_message_test!("not_errors/not_variant.rs", not_variant);
+error_message_test!("not_errors/not_keyword.rs", not_keyword);
@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Response {
+    code: u16,
+    body: String,
+}
+
+pub fn test_case() {
+    let response = Response {
+        code: 500,
+        body: "oops".to_string(),
+    };
+
+    assert_struct!(response, Response {
+        code: 200 | 201 | 204,
+        ..
+    });
+}
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let values = vec![1, 5, 9];
+
+    assert_struct!(values, [1 | 2, 3 | 4, > 5]);
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+enum Status {
+    Active,
+    Pending { since: u32 },
+    Closed(String),
+}
+
+#[derive(Debug)]
+struct Account {
+    status: Status,
+}
+
+pub fn test_case() {
+    let account = Account {
+        status: Status::Closed("fraud".to_string()),
+    };
+
+    assert_struct!(account, Account {
+        status: Status::Active | Status::Pending { since: > 0 },
+    });
+}
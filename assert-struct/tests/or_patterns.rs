@@ -0,0 +1,183 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, matches_struct};
+use std::cell::Cell;
+use util::{Counted, DEBUG_CALLS};
+
+#[macro_use]
+mod util;
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Active,
+    Pending { since: u32 },
+    Closed(String),
+}
+
+#[derive(Debug)]
+struct Response {
+    code: u16,
+    status: Status,
+    tags: Vec<String>,
+}
+
+fn response(code: u16, status: Status) -> Response {
+    Response {
+        code,
+        status,
+        tags: vec!["a".to_string(), "b".to_string()],
+    }
+}
+
+#[test]
+fn test_or_literals() {
+    let r = response(204, Status::Active);
+    assert_struct!(
+        r,
+        Response {
+            code: 200 | 204,
+            ..
+        }
+    );
+}
+
+#[test]
+fn test_or_enum_variants() {
+    let r = response(200, Status::Pending { since: 3 });
+    assert_struct!(
+        r,
+        Response {
+            status: Status::Active | Status::Pending { .. },
+            ..
+        }
+    );
+
+    let r = response(200, Status::Closed("done".to_string()));
+    assert_struct!(
+        r,
+        Response {
+            status: Status::Active | Status::Closed("done" | "cancelled"),
+            ..
+        }
+    );
+}
+
+#[test]
+fn test_or_mixed_pattern_kinds() {
+    let r = response(503, Status::Active);
+    assert_struct!(r, Response {
+        code: 200..300 | >= 500 | == 418,
+        ..
+    });
+}
+
+#[test]
+fn test_or_three_alternatives_last_matches() {
+    let value = Some(7);
+    assert_struct!(value, Some(1 | 3 | 7));
+}
+
+#[test]
+fn test_or_at_root() {
+    let value: Option<u32> = None;
+    assert_struct!(value, None | Some(0));
+}
+
+#[test]
+fn test_or_in_slice() {
+    let values = vec![1, 5, 9];
+    assert_struct!(values, [1 | 2, _, 8 | 9]);
+    assert_struct!(values, [0 | 1, ..]);
+}
+
+#[test]
+fn test_or_in_set() {
+    let values = vec![3, 10];
+    assert_struct!(values, #(10 | 20, 1 | 2 | 3));
+}
+
+#[test]
+fn test_or_in_map() {
+    let mut map = std::collections::HashMap::new();
+    map.insert(1, "admin");
+    assert_struct!(map, #{ 1: "admin" | "owner" });
+}
+
+#[test]
+fn test_or_in_tuple() {
+    let pair = (1, "b");
+    assert_struct!(pair, (1 | 2, "a" | "b"));
+}
+
+#[test]
+fn test_or_with_structural_alternatives() {
+    let r = response(200, Status::Active);
+    assert_struct!(
+        r,
+        Response { code: 404, .. } | Response { tags: [_, "b"], .. }
+    );
+}
+
+#[test]
+fn test_or_logical_or_stays_in_operand() {
+    let flag = true;
+    let a = false;
+    let b = true;
+    assert_struct!(flag, == a || b);
+}
+
+#[test]
+fn test_or_parenthesized_bitwise() {
+    const READ: u8 = 0b01;
+    const WRITE: u8 = 0b10;
+    let mode = 0b11u8;
+    assert_struct!(mode, == (READ | WRITE));
+}
+
+#[test]
+fn test_or_turbofish_operand() {
+    let total = 6;
+    assert_struct!(total, == [1, 2, 3].iter().sum::<i32>() | 0);
+}
+
+#[test]
+fn test_or_matches_struct() {
+    let r = response(500, Status::Active);
+    assert!(!matches_struct!(
+        r,
+        Response {
+            code: 200 | 204,
+            ..
+        }
+    ));
+    assert!(matches_struct!(
+        r,
+        Response {
+            code: 500 | 502,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_or_failed_alternatives_skip_formatting() {
+    let value = Counted(3);
+    assert_struct!(value, Counted(1) | Counted(2) | Counted(3));
+    assert_eq!(DEBUG_CALLS.with(Cell::get), 0);
+}
+
+#[test]
+#[should_panic(expected = "none of the alternatives matched")]
+fn test_or_failure_panics() {
+    let r = response(500, Status::Active);
+    assert_struct!(
+        r,
+        Response {
+            code: 200 | 204,
+            ..
+        }
+    );
+}
+
+error_message_test!("or_errors/literal_alternatives.rs", literal_alternatives);
+error_message_test!("or_errors/variant_alternatives.rs", variant_alternatives);
+error_message_test!("or_errors/or_in_slice.rs", or_in_slice);
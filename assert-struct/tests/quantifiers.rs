@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct};
+use std::collections::{BTreeSet, HashMap};
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    price: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Cart {
+    items: Vec<Item>,
+    coupons: Vec<Option<String>>,
+}
+
+fn item(name: &str, price: u32, tags: &[&str]) -> Item {
+    Item {
+        name: name.to_string(),
+        price,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_all_struct_elements() {
+    let c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    assert_struct!(c, Cart {
+        items: [all: Item { price: > 0, .. }],
+        ..
+    });
+}
+
+#[test]
+fn test_any_element() {
+    let c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    assert_struct!(c, Cart {
+        items: [any: { name: "bread" }],
+        coupons: [any: Some(_)],
+        ..
+    });
+}
+
+#[test]
+fn test_none_element() {
+    let c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    assert_struct!(c, Cart {
+        items: [none: { price: 0 }],
+        ..
+    });
+}
+
+#[test]
+fn test_all_on_empty_collection() {
+    let values: Vec<u32> = vec![];
+    assert_struct!(values, [all: > 100]);
+    assert_struct!(values, [none: _]);
+    assert!(check_struct!(values, [any: _]).is_err());
+}
+
+#[test]
+fn test_quantifiers_on_other_iterables() {
+    let set: BTreeSet<i32> = [1, 2, 3].into_iter().collect();
+    assert_struct!(set, [all: 1..=3]);
+
+    let array = [0.5_f64, 0.25];
+    assert_struct!(array, [all: ~ 0.4 +- 0.2]);
+
+    let mut map = HashMap::new();
+    map.insert("a", 1);
+    assert_struct!(map, [all: (_, > 0)]);
+}
+
+#[test]
+fn test_nested_quantifiers() {
+    let c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    assert_struct!(c, Cart {
+        items: [all: { tags: [all: != ""], .. }],
+        ..
+    });
+}
+
+#[test]
+fn test_quantifier_with_combinators() {
+    let values = vec![2, 4, 6];
+    assert_struct!(values, [all: > 0 && < 10]);
+    assert_struct!(values, [none: 1 | 3 | 5]);
+}
+
+#[test]
+fn test_all_reports_each_offending_index() {
+    let values = vec![5, 0, 7, 0];
+    let report = check_struct!(values, [all: > 0]).unwrap_err();
+    let labels: Vec<_> = report.failures().map(|f| f.to_string()).collect();
+    assert_eq!(labels, ["values[1]: got 0", "values[3]: got 0"]);
+}
+
+#[test]
+fn test_all_path_includes_fields() {
+    let mut c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    c.items[2].price = 0;
+    let report = check_struct!(c, Cart {
+        items: [all: Item { price: > 0, .. }],
+        ..
+    })
+    .unwrap_err();
+    assert_eq!(
+        report.failures().next().unwrap().to_string(),
+        "c.items[2].price: got 0"
+    );
+}
+
+#[test]
+fn test_none_reports_matching_elements() {
+    let c = Cart {
+        items: vec![
+            item("apple", 3, &["fruit"]),
+            item("bread", 2, &[]),
+            item("cheese", 7, &["dairy", "aged"]),
+        ],
+        coupons: vec![None, Some("SAVE5".to_string())],
+    };
+    let report = check_struct!(c, Cart {
+        coupons: [none: Some(_)],
+        ..
+    })
+    .unwrap_err();
+    assert_eq!(
+        report.failures().next().unwrap().to_string(),
+        "c.coupons[1]: expected no element to match Some(...), got Some(\"SAVE5\")"
+    );
+}
+
+error_message_test!("quantifiers_errors/all_fails.rs", all_fails);
+error_message_test!("quantifiers_errors/any_fails.rs", any_fails);
+error_message_test!("quantifiers_errors/none_fails.rs", none_fails);
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    price: u32,
+}
+
+#[derive(Debug)]
+struct Order {
+    items: Vec<Item>,
+}
+
+pub fn test_case() {
+    let order = Order {
+        items: vec![
+            Item { name: "pen".to_string(), price: 2 },
+            Item { name: "gift".to_string(), price: 0 },
+            Item { name: "ink".to_string(), price: 5 },
+            Item { name: "sample".to_string(), price: 0 },
+        ],
+    };
+
+    assert_struct!(order, Order {
+        items: [all: Item { price: > 0, .. }],
+    });
+}
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let roles = vec!["viewer", "editor"];
+
+    assert_struct!(roles, [any: "admin" | "owner"]);
+}
@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let readings = vec![Some(3), None, Some(8), None];
+
+    assert_struct!(readings, [none: None]);
+}
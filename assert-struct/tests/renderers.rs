@@ -0,0 +1,85 @@
+use assert_struct::render::{GitHub, Human, Json, Render};
+use assert_struct::{ErrorReport, check_struct};
+use std::fmt;
+
+#[derive(Debug)]
+struct Job {
+    name: String,
+    attempts: u32,
+    exit_code: i32,
+}
+
+#[test]
+fn test_github_renders_one_command_per_failure() {
+    let job = Job {
+        name: "deploy".to_string(),
+        attempts: 3,
+        exit_code: 1,
+    };
+    let report = check_struct!(
+        job,
+        Job {
+            name: "build",
+            exit_code: 0,
+            ..
+        }
+    )
+    .unwrap_err();
+
+    insta::assert_snapshot!(report.render(&GitHub));
+}
+
+#[test]
+fn test_github_escapes_title_and_message() {
+    let job = Job {
+        name: "deploy".to_string(),
+        attempts: 3,
+        exit_code: 1,
+    };
+    let report =
+        check_struct!(job, Job { attempts: < 3, .. }, "job {}, step: {}", 7, "push").unwrap_err();
+
+    insta::assert_snapshot!(report.render(&GitHub));
+}
+
+#[test]
+fn test_builtin_renderers_match_report_methods() {
+    let job = Job {
+        name: "deploy".to_string(),
+        attempts: 3,
+        exit_code: 1,
+    };
+    let report = check_struct!(job, Job { attempts: 1, .. }).unwrap_err();
+
+    assert_eq!(report.render(&Json), report.to_json());
+    let _guard = assert_struct::__macro_support::PlainOutputGuard::new();
+    assert_eq!(report.render(&Human), report.to_string());
+}
+
+struct Count;
+
+impl Render for Count {
+    fn render(&self, report: &ErrorReport, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{} failures", report.len())
+    }
+}
+
+#[test]
+fn test_custom_renderer() {
+    let job = Job {
+        name: "deploy".to_string(),
+        attempts: 3,
+        exit_code: 1,
+    };
+    let report = check_struct!(
+        job,
+        Job {
+            name: "build",
+            attempts: 0,
+            exit_code: 0,
+        }
+    )
+    .unwrap_err();
+
+    assert_eq!(report.render(&Count), "3 failures");
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 use assert_struct::assert_struct;
+use std::cell::Cell;
+use util::{Counted, DEBUG_CALLS};
 
 #[macro_use]
 mod util;
@@ -128,23 +130,52 @@ fn test_set_struct_patterns_with_rest() {
     ));
 }
 
-// ── Backtracking correctness ──────────────────────────────────────────────────
+// ── Assignment correctness ────────────────────────────────────────────────────
 
 #[test]
 fn test_set_backtracking_required() {
     // Greedy would fail: > 5 claims 10, then == 10 fails on 7.
-    // Backtracking reassigns: > 5 → 7, == 10 → 10.
+    // The matching reassigns: > 5 → 7, == 10 → 10.
     let items = vec![10, 7];
     assert_struct!(items, #(> 5, == 10));
 }
 
 #[test]
 fn test_set_backtracking_overlapping_patterns() {
-    // All elements satisfy > 0; backtracking must assign distinctly
+    // All elements satisfy > 0; the matching must assign distinctly
     let items = vec![1, 2, 3];
     assert_struct!(items, #(> 0, > 0, > 0));
 }
 
+#[test]
+fn test_set_large_overlapping_unsatisfiable() {
+    // Every assignment of the `> 0` patterns has to be ruled out before the
+    // `== -1` pattern can fail; an exhaustive search would never finish here.
+    let items: Vec<i32> = (1..=300).collect();
+    assert!(!assert_struct::matches_struct!(
+        items,
+        #(> 0, > 0, > 0, > 0, > 0, > 0, > 0, > 0, > 0, > 0, == -1, ..)
+    ));
+}
+
+#[test]
+fn test_set_large_chain_requires_reassignment() {
+    // Pattern i accepts elements i and i + 1, so greedy choices must be undone
+    // along the whole chain for the final `== 0` pattern to find element 0.
+    let items: Vec<i32> = (0..8).collect();
+    assert_struct!(items, #(1..=2, 2..=3, 3..=4, 4..=5, 5..=6, 6..=7, 1..=7, == 0));
+}
+
+// ── Probe cost ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_set_probes_skip_formatting() {
+    // Every pattern fails against most elements while the assignment is searched
+    let items = vec![Counted(3), Counted(1), Counted(2)];
+    assert_struct!(items, #(Counted(1), Counted(2), Counted(3)));
+    assert_eq!(DEBUG_CALLS.with(Cell::get), 0);
+}
+
 // ── Enum patterns inside #() ──────────────────────────────────────────────────
 
 #[test]
@@ -202,3 +233,15 @@ error_message_test!(
     test_set_no_valid_assignment
 );
 error_message_test!("sets_errors/empty_mismatch.rs", test_set_empty_mismatch);
+error_message_test!(
+    "sets_errors/closest_struct_elements.rs",
+    test_set_closest_struct_elements
+);
+error_message_test!(
+    "sets_errors/element_already_paired.rs",
+    test_set_element_already_paired
+);
+error_message_test!(
+    "sets_errors/several_unmatched.rs",
+    test_set_several_unmatched
+);
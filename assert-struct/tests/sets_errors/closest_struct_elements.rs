@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Event {
+    kind: String,
+    value: i32,
+}
+
+pub fn test_case() {
+    // The click pattern fails on one field of the first event and two of the others
+    let events = vec![
+        Event {
+            kind: "click".to_string(),
+            value: 0,
+        },
+        Event {
+            kind: "hover".to_string(),
+            value: 20,
+        },
+        Event {
+            kind: "scroll".to_string(),
+            value: -5,
+        },
+    ];
+    assert_struct!(events, #(
+        { kind: "hover", .. },
+        { kind: "click", value: > 0 },
+        ..
+    ));
+}
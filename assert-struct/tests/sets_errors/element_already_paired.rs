@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    // Both patterns only accept 5, which can only be paired once
+    let items = vec![5, 7];
+    assert_struct!(items, #(== 5, 1..=5));
+}
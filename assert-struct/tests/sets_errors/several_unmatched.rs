@@ -0,0 +1,7 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+pub fn test_case() {
+    let items = vec![1, 2, 3, 4];
+    assert_struct!(items, #(> 10, 2, < 0, ..));
+}
@@ -516,6 +516,18 @@ error_message_test!(
 
 error_message_test!("slices_errors/slice_pattern.rs", slice_pattern);
 
+error_message_test!(
+    "slices_errors/slice_length_mismatch.rs",
+    slice_length_mismatch
+);
+
+error_message_test!(
+    "slices_errors/slice_suffix_mismatch.rs",
+    slice_suffix_mismatch
+);
+
+error_message_test!("slices_errors/nested_element_path.rs", nested_element_path);
+
 // Slice path testing - error message path tracking for slice elements
 #[derive(Debug)]
 struct SlicePathData {
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Order {
+    items: Vec<Item>,
+}
+
+#[derive(Debug)]
+struct Item {
+    name: String,
+    quantity: u32,
+}
+
+pub fn test_case() {
+    let order = Order {
+        items: vec![
+            Item {
+                name: "pen".to_string(),
+                quantity: 2,
+            },
+            Item {
+                name: "ink".to_string(),
+                quantity: 1,
+            },
+            Item {
+                name: "pad".to_string(),
+                quantity: 3,
+            },
+        ],
+    };
+
+    assert_struct!(
+        order,
+        Order {
+            items: [
+                _,
+                Item { quantity: 1, .. },
+                Item {
+                    name: "paper",
+                    quantity: > 0,
+                },
+            ],
+        }
+    );
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Batch {
+    ids: Vec<u32>,
+    tags: Vec<String>,
+}
+
+pub fn test_case() {
+    let batch = Batch {
+        ids: vec![1, 2, 3, 4, 5],
+        tags: vec!["new".to_string()],
+    };
+
+    assert_struct!(
+        batch,
+        Batch {
+            ids: [1, 2, 3],
+            tags: ["new", "sale", ..],
+        }
+    );
+}
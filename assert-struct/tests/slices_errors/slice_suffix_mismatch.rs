@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use assert_struct::assert_struct;
+
+#[derive(Debug)]
+struct Log {
+    events: Vec<&'static str>,
+}
+
+pub fn test_case() {
+    let log = Log {
+        events: vec!["start", "load", "save", "crash"],
+    };
+
+    assert_struct!(
+        log,
+        Log {
+            events: ["start", .., "stop"],
+        }
+    );
+}
@@ -0,0 +1,89 @@
+#![allow(dead_code)]
+use assert_struct::{assert_struct, check_struct, soft};
+use std::cell::Cell;
+use std::panic;
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+pub struct Service {
+    pub name: &'static str,
+    pub healthy: bool,
+    pub latency_ms: u32,
+}
+
+pub fn services() -> Vec<Service> {
+    vec![
+        Service {
+            name: "api",
+            healthy: true,
+            latency_ms: 40,
+        },
+        Service {
+            name: "db",
+            healthy: false,
+            latency_ms: 900,
+        },
+    ]
+}
+
+/// Asserted from the test case in another file.
+pub fn assert_fast(service: &Service) {
+    assert_struct!(service, Service { latency_ms: < 100, .. });
+}
+
+error_message_test!(
+    "soft_errors/reports_from_two_files.rs",
+    reports_from_two_files
+);
+
+#[test]
+fn test_soft_passes_through_result() {
+    let services = services();
+    let name = soft(|| {
+        assert_struct!(services[0], Service { healthy: true, .. });
+        services[0].name
+    });
+    assert_eq!(name, "api");
+}
+
+#[test]
+fn test_soft_continues_after_failure() {
+    let reached = Cell::new(false);
+    let message = util::capture_panic_message(panic::AssertUnwindSafe(|| {
+        soft(|| {
+            assert_struct!(1, 2);
+            reached.set(true);
+        });
+    }));
+    assert!(reached.get());
+    assert!(message.contains("1: got 1"));
+}
+
+#[test]
+fn test_nested_soft_reports_to_outer() {
+    let message = util::capture_panic_message(|| {
+        soft(|| {
+            soft(|| assert_struct!(1, 2));
+            assert_struct!(3, 4);
+        });
+    });
+    assert_eq!(message.matches("assert_struct! failed").count(), 2);
+}
+
+#[test]
+fn test_soft_leaves_check_struct_alone() {
+    let result = soft(|| check_struct!(1, 2));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_soft_scope_ends_on_panic() {
+    let _ = panic::catch_unwind(|| {
+        soft(|| panic!("unrelated"));
+    });
+    // Outside any scope again, so the assertion panics on its own
+    let message = util::capture_panic_message(|| assert_struct!(1, 2));
+    assert!(message.contains("1: got 1"));
+}
@@ -0,0 +1,12 @@
+use super::{Service, assert_fast, services};
+use assert_struct::{assert_struct, soft};
+
+pub fn test_case() {
+    let services = services();
+    soft(|| {
+        for service in &services {
+            assert_struct!(service, Service { healthy: true, .. });
+            assert_fast(service);
+        }
+    });
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+use assert_struct::{ErrorReport, assert_struct, check_struct};
+
+#[macro_use]
+mod util;
+
+#[derive(Debug)]
+pub struct User {
+    pub name: &'static str,
+    pub age: u32,
+}
+
+/// A helper in another file than the test cases calling it.
+#[track_caller]
+pub fn assert_adult(user: &User) {
+    assert_struct!(user, User { age: >= 18, .. });
+}
+
+#[track_caller]
+fn check_named(user: &User) -> Result<(), ErrorReport> {
+    check_struct!(user, User { name: != "", .. })
+}
+
+fn check_named_untracked(user: &User) -> Result<(), ErrorReport> {
+    check_struct!(user, User { name: != "", .. })
+}
+
+error_message_test!(
+    "track_caller_errors/helper_in_same_file.rs",
+    helper_in_same_file
+);
+error_message_test!(
+    "track_caller_errors/helper_in_other_file.rs",
+    helper_in_other_file
+);
+
+#[test]
+fn test_caller_recorded_in_track_caller_function() {
+    let user = User { name: "", age: 30 };
+    let report = check_named(&user).unwrap_err();
+    let caller = report.caller().unwrap();
+    assert_eq!(caller.file(), "assert-struct/tests/track_caller.rs");
+    assert_eq!(caller.line(), 40);
+}
+
+#[test]
+fn test_no_caller_outside_track_caller_function() {
+    let user = User { name: "", age: 30 };
+    assert!(check_named_untracked(&user).unwrap_err().caller().is_none());
+
+    let report = check_struct!(user, User { age: 31, .. }).unwrap_err();
+    assert!(report.caller().is_none());
+}
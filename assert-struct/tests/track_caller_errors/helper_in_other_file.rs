@@ -0,0 +1,9 @@
+use super::{User, assert_adult};
+
+pub fn test_case() {
+    let user = User {
+        name: "Ada",
+        age: 12,
+    };
+    assert_adult(&user);
+}
@@ -0,0 +1,12 @@
+use super::User;
+use assert_struct::assert_struct;
+
+#[track_caller]
+fn assert_named(user: &User) {
+    assert_struct!(user, User { name: != "", .. });
+}
+
+pub fn test_case() {
+    let user = User { name: "", age: 30 };
+    assert_named(&user);
+}
@@ -1,5 +1,7 @@
 // Common test utilities
 
+use std::cell::Cell;
+use std::fmt;
 use std::panic;
 
 /// Captures the panic message from a function that should panic.
@@ -46,3 +48,22 @@ macro_rules! error_message_test {
         }
     };
 }
+
+// Not every test file that includes this module uses the helpers below
+thread_local! {
+    /// How often a [`Counted`] was formatted with `Debug` on this thread.
+    #[allow(dead_code)]
+    pub static DEBUG_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts how often it is formatted with `Debug`, for tests checking that
+/// probes don't format values.
+#[allow(dead_code)]
+pub struct Counted(pub i32);
+
+impl fmt::Debug for Counted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DEBUG_CALLS.with(|calls| calls.set(calls.get() + 1));
+        write!(f, "Counted({})", self.0)
+    }
+}